@@ -1,4 +1,7 @@
 // Simple bhumi-gui using pixels crate (no complex wgpu setup)
+mod main_complex;
+mod main_empty;
+
 use bhumi::{Renderer, InputEvent};
 use log::info;
 use pixels::{Pixels, SurfaceTexture};
@@ -276,7 +279,21 @@ impl ApplicationHandler for BhumiGpuApp {
 
 fn main() {
     env_logger::init();
-    
+
+    // --wgpu selects the alternative wgpu-backed app (its own swapchain/render-pass setup with
+    // a low-res retro render target, mouse-look, and an action-binding input layer), instead of
+    // the default pixels-backed one below.
+    if std::env::args().any(|arg| arg == "--wgpu") {
+        main_empty::run();
+        return;
+    }
+    // --wgpu-3d selects the full GPU 3D app (depth buffer, instancing, HDR tonemapping) instead
+    // of the default pixels-backed one below.
+    if std::env::args().any(|arg| arg == "--wgpu-3d") {
+        main_complex::run();
+        return;
+    }
+
     let event_loop = EventLoop::new().unwrap();
     // Use continuous polling like terminal version for debugging
     event_loop.set_control_flow(ControlFlow::Poll);
@@ -1,7 +1,7 @@
 // Fresh bhumi-gui with proper GPU acceleration
 use bhumi::{PhysicsWorld, Camera, InputEvent};
 use gilrs::{Gilrs, Button, Axis};
-use glam::{Vec3, Mat4};
+use glam::{Vec3, Mat4, Quat};
 use log::info;
 use std::collections::HashSet;
 use std::time::Instant;
@@ -17,6 +17,221 @@ use winit::{
 
 const RENDER_WIDTH: u32 = 320;
 const RENDER_HEIGHT: u32 = 240;
+const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
+const HDR_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Rgba16Float;
+/// Fixed physics timestep, independent of display frame rate.
+const PHYSICS_DT: f32 = 1.0 / 120.0;
+/// Clamp on a single frame's elapsed time, so a debugger pause or tab-switch hitch doesn't dump a
+/// huge backlog of physics steps into the accumulator loop all at once.
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// Standard learn-wgpu depth texture: same size as the surface, recreated on resize.
+fn create_depth_texture(
+    device: &wgpu::Device,
+    config: &wgpu::SurfaceConfiguration,
+) -> (wgpu::Texture, wgpu::TextureView) {
+    let depth_texture = device.create_texture(&wgpu::TextureDescriptor {
+        label: Some("depth_texture"),
+        size: wgpu::Extent3d {
+            width: config.width,
+            height: config.height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: DEPTH_FORMAT,
+        usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let depth_view = depth_texture.create_view(&wgpu::TextureViewDescriptor::default());
+    (depth_texture, depth_view)
+}
+
+/// Renders the scene into an `Rgba16Float` target, then tonemaps (ACES filmic) into the sRGB
+/// swapchain in a full-screen post pass. Follows the learn-wgpu HDR tutorial's `HdrPipeline`
+/// shape; leaves room for a later bloom pass to sample the same float texture.
+struct HdrPipeline {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    bind_group_layout: wgpu::BindGroupLayout,
+    sampler: wgpu::Sampler,
+    pipeline: wgpu::RenderPipeline,
+}
+
+impl HdrPipeline {
+    fn new(device: &wgpu::Device, surface_format: wgpu::TextureFormat, width: u32, height: u32) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("hdr_sampler"),
+            mag_filter: wgpu::FilterMode::Linear,
+            min_filter: wgpu::FilterMode::Linear,
+            ..Default::default()
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("hdr_bind_group_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let (texture, view, bind_group) =
+            Self::create_target(device, &bind_group_layout, &sampler, width, height);
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("hdr_tonemap_shader"),
+            source: wgpu::ShaderSource::Wgsl(TONEMAP_SHADER.into()),
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("hdr_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("hdr_tonemap_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: "vs_main",
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: "fs_main",
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        Self {
+            texture,
+            view,
+            bind_group,
+            bind_group_layout,
+            sampler,
+            pipeline,
+        }
+    }
+
+    fn create_target(
+        device: &wgpu::Device,
+        bind_group_layout: &wgpu::BindGroupLayout,
+        sampler: &wgpu::Sampler,
+        width: u32,
+        height: u32,
+    ) -> (wgpu::Texture, wgpu::TextureView, wgpu::BindGroup) {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("hdr_texture"),
+            size: wgpu::Extent3d { width, height, depth_or_array_layers: 1 },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: HDR_FORMAT,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("hdr_bind_group"),
+            layout: bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry { binding: 0, resource: wgpu::BindingResource::TextureView(&view) },
+                wgpu::BindGroupEntry { binding: 1, resource: wgpu::BindingResource::Sampler(sampler) },
+            ],
+        });
+        (texture, view, bind_group)
+    }
+
+    /// Recreates just the float texture/view/bind group at the new surface size.
+    fn resize(&mut self, device: &wgpu::Device, width: u32, height: u32) {
+        let (texture, view, bind_group) =
+            Self::create_target(device, &self.bind_group_layout, &self.sampler, width, height);
+        self.texture = texture;
+        self.view = view;
+        self.bind_group = bind_group;
+    }
+
+    /// Scene target to render into, in place of the swapchain view.
+    fn view(&self) -> &wgpu::TextureView {
+        &self.view
+    }
+
+    /// Tonemaps the HDR texture into `target` (the swapchain view) via a full-screen triangle.
+    fn render(&self, encoder: &mut wgpu::CommandEncoder, target: &wgpu::TextureView) {
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("hdr_tonemap_pass"),
+            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                view: target,
+                resolve_target: None,
+                ops: wgpu::Operations { load: wgpu::LoadOp::Clear(wgpu::Color::BLACK), store: wgpu::StoreOp::Store },
+            })],
+            depth_stencil_attachment: None,
+            occlusion_query_set: None,
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(&self.pipeline);
+        pass.set_bind_group(0, &self.bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
+
+const TONEMAP_SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+}
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let uv = vec2<f32>(f32((vertex_index << 1u) & 2u), f32(vertex_index & 2u));
+    out.uv = uv;
+    out.clip_position = vec4<f32>(uv * vec2<f32>(2.0, -2.0) + vec2<f32>(-1.0, 1.0), 0.0, 1.0);
+    return out;
+}
+
+@group(0) @binding(0) var hdr_texture: texture_2d<f32>;
+@group(0) @binding(1) var hdr_sampler: sampler;
+
+// ACES filmic tonemap curve (Narkowicz fit), clamped to the display range.
+fn aces_tonemap(x: vec3<f32>) -> vec3<f32> {
+    let numerator = x * (2.51 * x + 0.03);
+    let denominator = x * (2.43 * x + 0.59) + 0.14;
+    return clamp(numerator / denominator, vec3<f32>(0.0), vec3<f32>(1.0));
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let hdr_color = textureSample(hdr_texture, hdr_sampler, in.uv).rgb;
+    return vec4<f32>(aces_tonemap(hdr_color), 1.0);
+}
+"#;
 
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
@@ -48,6 +263,135 @@ impl Vertex {
     }
 }
 
+/// Loads real geometry from OBJ text instead of the procedural test triangle, modeled on the
+/// learn-wgpu `model`/`DrawModel` pattern minus the material/texture handling we don't need yet.
+mod mesh {
+    use super::Vertex;
+    use wgpu::util::DeviceExt;
+
+    pub struct Mesh {
+        pub vertex_buffer: wgpu::Buffer,
+        pub index_buffer: wgpu::Buffer,
+        pub index_count: u32,
+    }
+
+    /// Minimal OBJ subset: `v x y z` positions and `f a b c ...` faces (1-indexed, fan
+    /// triangulated). No `tobj` dependency in this codebase, so this stays hand-rolled like the
+    /// terminal renderer's own Kitty/base64 encoders.
+    fn parse_obj(source: &str) -> (Vec<[f32; 3]>, Vec<u32>) {
+        let mut positions = Vec::new();
+        let mut indices = Vec::new();
+
+        for line in source.lines() {
+            let mut tokens = line.split_whitespace();
+            match tokens.next() {
+                Some("v") => {
+                    let mut coords = tokens.filter_map(|t| t.parse::<f32>().ok());
+                    if let (Some(x), Some(y), Some(z)) = (coords.next(), coords.next(), coords.next()) {
+                        positions.push([x, y, z]);
+                    }
+                }
+                Some("f") => {
+                    let face: Vec<u32> = tokens
+                        .filter_map(|t| t.split('/').next())
+                        .filter_map(|t| t.parse::<u32>().ok())
+                        .map(|one_indexed| one_indexed - 1)
+                        .collect();
+                    for i in 1..face.len().saturating_sub(1) {
+                        indices.push(face[0]);
+                        indices.push(face[i]);
+                        indices.push(face[i + 1]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        (positions, indices)
+    }
+
+    pub fn load_obj(device: &wgpu::Device, source: &str, color: [f32; 3]) -> Mesh {
+        let (positions, indices) = parse_obj(source);
+        let vertices: Vec<Vertex> = positions
+            .into_iter()
+            .map(|position| Vertex { position, color })
+            .collect();
+
+        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("mesh_vertex_buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: wgpu::BufferUsages::VERTEX,
+        });
+        let index_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("mesh_index_buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: wgpu::BufferUsages::INDEX,
+        });
+
+        Mesh {
+            vertex_buffer,
+            index_buffer,
+            index_count: indices.len() as u32,
+        }
+    }
+}
+
+/// Placeholder drone/obstacle geometry until real assets are authored, kept inline so a fresh
+/// checkout renders something without needing an `assets/` directory to exist.
+const CUBE_OBJ: &str = "\
+v -0.5 -0.5 -0.5
+v 0.5 -0.5 -0.5
+v 0.5 0.5 -0.5
+v -0.5 0.5 -0.5
+v -0.5 -0.5 0.5
+v 0.5 -0.5 0.5
+v 0.5 0.5 0.5
+v -0.5 0.5 0.5
+f 1 2 3 4
+f 5 8 7 6
+f 1 5 6 2
+f 2 6 7 3
+f 3 7 8 4
+f 4 8 5 1
+";
+
+/// One drawable instance (the drone, an obstacle, ...), reduced to a model matrix for the GPU.
+struct Instance {
+    position: Vec3,
+    rotation: Quat,
+}
+
+impl Instance {
+    fn to_raw(&self) -> InstanceRaw {
+        InstanceRaw {
+            model: Mat4::from_rotation_translation(self.rotation, self.position).to_cols_array_2d(),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+struct InstanceRaw {
+    model: [[f32; 4]; 4],
+}
+
+impl InstanceRaw {
+    const ATTRIBS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+        2 => Float32x4,
+        3 => Float32x4,
+        4 => Float32x4,
+        5 => Float32x4,
+    ];
+
+    fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
+        wgpu::VertexBufferLayout {
+            array_stride: std::mem::size_of::<InstanceRaw>() as wgpu::BufferAddress,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &Self::ATTRIBS,
+        }
+    }
+}
+
 struct BhumiGpuApp {
     // Core 3D engine
     physics: PhysicsWorld,
@@ -59,10 +403,15 @@ struct BhumiGpuApp {
     config: Option<wgpu::SurfaceConfiguration>,
     surface: Option<wgpu::Surface<'static>>,
     render_pipeline: Option<wgpu::RenderPipeline>,
-    vertex_buffer: Option<wgpu::Buffer>,
+    meshes: Vec<mesh::Mesh>,
     uniform_buffer: Option<wgpu::Buffer>,
     uniform_bind_group: Option<wgpu::BindGroup>,
-    
+    depth_texture: Option<wgpu::Texture>,
+    depth_view: Option<wgpu::TextureView>,
+    hdr: Option<HdrPipeline>,
+    instance_buffer: Option<wgpu::Buffer>,
+    instance_count: u32,
+
     // Window and input
     window: Option<Window>,
     keys_pressed: HashSet<KeyCode>,
@@ -72,6 +421,8 @@ struct BhumiGpuApp {
     last_frame: Instant,
     frame_count: u64,
     is_fullscreen: bool,
+    /// Leftover real time not yet consumed by a `PHYSICS_DT` step.
+    accumulator: f32,
     
     // Physics state
     thrust_force: Vec3,
@@ -99,10 +450,15 @@ impl BhumiGpuApp {
             config: None,
             surface: None,
             render_pipeline: None,
-            vertex_buffer: None,
+            meshes: Vec::new(),
             uniform_buffer: None,
             uniform_bind_group: None,
-            
+            depth_texture: None,
+            depth_view: None,
+            hdr: None,
+            instance_buffer: None,
+            instance_count: 0,
+
             // Window and input
             window: None,
             keys_pressed: HashSet::new(),
@@ -115,6 +471,7 @@ impl BhumiGpuApp {
             last_frame: Instant::now(),
             frame_count: 0,
             is_fullscreen: false,
+            accumulator: 0.0,
             thrust_force: Vec3::ZERO,
             rotation_delta: Vec3::ZERO,
             stopping_mode: StoppingMode::None,
@@ -227,21 +584,21 @@ impl BhumiGpuApp {
             vertex: wgpu::VertexState {
                 module: &shader,
                 entry_point: "vs_main",
-                buffers: &[Vertex::desc()],
+                buffers: &[Vertex::desc(), InstanceRaw::desc()],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             },
             fragment: Some(wgpu::FragmentState {
                 module: &shader,
                 entry_point: "fs_main",
                 targets: &[Some(wgpu::ColorTargetState {
-                    format: config.format,
+                    format: HDR_FORMAT,
                     blend: Some(wgpu::BlendState::ALPHA_BLENDING),
                     write_mask: wgpu::ColorWrites::ALL,
                 })],
                 compilation_options: wgpu::PipelineCompilationOptions::default(),
             }),
             primitive: wgpu::PrimitiveState {
-                topology: wgpu::PrimitiveTopology::LineList,
+                topology: wgpu::PrimitiveTopology::TriangleList,
                 strip_index_format: None,
                 front_face: wgpu::FrontFace::Ccw,
                 cull_mode: None,
@@ -249,47 +606,56 @@ impl BhumiGpuApp {
                 polygon_mode: wgpu::PolygonMode::Fill,
                 conservative: false,
             },
-            depth_stencil: None,
+            depth_stencil: Some(wgpu::DepthStencilState {
+                format: DEPTH_FORMAT,
+                depth_write_enabled: true,
+                depth_compare: wgpu::CompareFunction::Less,
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
             multisample: wgpu::MultisampleState::default(),
             multiview: None,
             cache: None,
         });
         
-        // Generate wireframe cube vertices
-        let vertices = self.generate_cube_wireframe_vertices();
-        let vertex_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-            label: Some("vertex_buffer"),
-            contents: bytemuck::cast_slice(&vertices),
-            usage: wgpu::BufferUsages::VERTEX,
-        });
-        
+        // Load the placeholder drone mesh from OBJ rather than hardcoding triangle data.
+        let meshes = vec![mesh::load_obj(&device, CUBE_OBJ, [1.0, 1.0, 1.0])];
+
         info!("GPU initialized: {}Ã—{} @ {:?}", size.width, size.height, surface_format);
-        
+
+        let (depth_texture, depth_view) = create_depth_texture(&device, &config);
+        let hdr = HdrPipeline::new(&device, config.format, config.width, config.height);
+
         // Store everything
         self.device = Some(device);
         self.queue = Some(queue);
         self.config = Some(config);
         self.surface = Some(surface);
         self.render_pipeline = Some(render_pipeline);
-        self.vertex_buffer = Some(vertex_buffer);
+        self.meshes = meshes;
         self.uniform_buffer = Some(uniform_buffer);
         self.uniform_bind_group = Some(uniform_bind_group);
+        self.depth_texture = Some(depth_texture);
+        self.depth_view = Some(depth_view);
+        self.hdr = Some(hdr);
+
+        // Default to a single instance at the origin until the physics world supplies real ones.
+        self.set_instances(&[Instance { position: Vec3::ZERO, rotation: Quat::IDENTITY }]);
     }
-    
-    fn generate_cube_wireframe_vertices(&self) -> Vec<Vertex> {
-        // Simple test cube wireframe
-        let white = [1.0, 1.0, 1.0];
-        let red = [1.0, 0.0, 0.0];
-        
-        vec![
-            // Test triangle
-            Vertex { position: [-0.5, -0.5, 0.0], color: red },
-            Vertex { position: [0.5, -0.5, 0.0], color: white },
-            Vertex { position: [0.0, 0.5, 0.0], color: white },
-            // More vertices will be generated procedurally later
-        ]
+
+    /// Rebuilds the instance buffer (drone plus any obstacles) so `render()` can draw the whole
+    /// field in a single `draw(..)` call instead of one draw per object.
+    fn set_instances(&mut self, instances: &[Instance]) {
+        let Some(ref device) = self.device else { return };
+        let raw: Vec<InstanceRaw> = instances.iter().map(Instance::to_raw).collect();
+        self.instance_buffer = Some(device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("instance_buffer"),
+            contents: bytemuck::cast_slice(&raw),
+            usage: wgpu::BufferUsages::VERTEX,
+        }));
+        self.instance_count = instances.len() as u32;
     }
-    
+
     fn handle_input(&mut self) {
         // Handle gamepad input
         while let Some(gilrs::Event { id: _, event, time: _ }) = self.gamepad.next_event() {
@@ -321,14 +687,17 @@ impl BhumiGpuApp {
         // TODO: Apply gamepad analog sticks
     }
     
-    fn update_physics(&mut self, dt: f32) {
+    /// Advances the simulation by exactly `PHYSICS_DT`, independent of how long the real frame
+    /// took. Called in a `while accumulator >= PHYSICS_DT` loop so behavior (damping, rotation
+    /// integration) stops depending on frame rate.
+    fn step_physics(&mut self) {
         // Apply stopping modes
         match self.stopping_mode {
             StoppingMode::Gentle => self.physics.gentle_stop(),
             StoppingMode::Emergency => self.physics.emergency_brake(),
             StoppingMode::None => {},
         }
-        
+
         // Apply rotation delta if any
         if self.rotation_delta.length() > 0.001 {
             let rotation_delta_rapier = rapier3d::prelude::Vector::new(
@@ -336,27 +705,26 @@ impl BhumiGpuApp {
             );
             self.physics.apply_rotation_delta(rotation_delta_rapier);
         }
-        
+
         // Step physics
         let thrust_rapier = rapier3d::prelude::Vector::new(
             self.thrust_force.x, self.thrust_force.y, self.thrust_force.z
         );
-        let drone_pos = self.physics.step(dt, thrust_rapier);
-        let drone_rot = self.physics.get_drone_rotation();
-        
-        // Update camera
-        self.camera.update(drone_pos, drone_rot);
+        self.physics.step(PHYSICS_DT, thrust_rapier);
     }
-    
-    fn render(&mut self) {
-        let Some(ref device) = self.device else { return };
-        let Some(ref queue) = self.queue else { return };
-        let Some(ref surface) = self.surface else { return };
-        let Some(ref render_pipeline) = self.render_pipeline else { return };
-        let Some(ref vertex_buffer) = self.vertex_buffer else { return };
-        let Some(ref uniform_buffer) = self.uniform_buffer else { return };
-        let Some(ref uniform_bind_group) = self.uniform_bind_group else { return };
-        
+
+    fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        let Some(ref device) = self.device else { return Ok(()) };
+        let Some(ref queue) = self.queue else { return Ok(()) };
+        let Some(ref surface) = self.surface else { return Ok(()) };
+        let Some(ref config) = self.config else { return Ok(()) };
+        let Some(ref render_pipeline) = self.render_pipeline else { return Ok(()) };
+        let Some(ref uniform_buffer) = self.uniform_buffer else { return Ok(()) };
+        let Some(ref uniform_bind_group) = self.uniform_bind_group else { return Ok(()) };
+        let Some(ref depth_view) = self.depth_view else { return Ok(()) };
+        let Some(ref instance_buffer) = self.instance_buffer else { return Ok(()) };
+        let Some(ref hdr) = self.hdr else { return Ok(()) };
+
         // Update uniforms
         let view_proj = self.camera.get_view_projection_matrix();
         let uniforms = Uniforms {
@@ -372,8 +740,16 @@ impl BhumiGpuApp {
         
         queue.write_buffer(uniform_buffer, 0, bytemuck::cast_slice(&[uniforms]));
         
-        // Get surface texture
-        let output = surface.get_current_texture().unwrap();
+        // Get surface texture, recovering from the errors that are routine during resizes,
+        // monitor changes, or GPU resets instead of panicking.
+        let output = match surface.get_current_texture() {
+            Ok(output) => output,
+            Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                surface.configure(device, config);
+                return Ok(());
+            }
+            Err(e) => return Err(e),
+        };
         let view = output.texture.create_view(&wgpu::TextureViewDescriptor::default());
         
         // Create command encoder
@@ -381,12 +757,12 @@ impl BhumiGpuApp {
             label: Some("render_encoder"),
         });
         
-        // Render pass
+        // Scene pass: render into the HDR float target rather than the sRGB swapchain directly.
         {
             let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
                 label: Some("render_pass"),
                 color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: &view,
+                    view: hdr.view(),
                     resolve_target: None,
                     ops: wgpu::Operations {
                         load: wgpu::LoadOp::Clear(wgpu::Color {
@@ -398,23 +774,37 @@ impl BhumiGpuApp {
                         store: wgpu::StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: None,
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: depth_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(1.0),
+                        store: wgpu::StoreOp::Store,
+                    }),
+                    stencil_ops: None,
+                }),
                 occlusion_query_set: None,
                 timestamp_writes: None,
             });
             
             render_pass.set_pipeline(render_pipeline);
             render_pass.set_bind_group(0, uniform_bind_group, &[]);
-            render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
-            
-            // Draw wireframe (for now just test triangle)
-            render_pass.draw(0..3, 0..1);
+            render_pass.set_vertex_buffer(1, instance_buffer.slice(..));
+
+            for mesh in &self.meshes {
+                render_pass.set_vertex_buffer(0, mesh.vertex_buffer.slice(..));
+                render_pass.set_index_buffer(mesh.index_buffer.slice(..), wgpu::IndexFormat::Uint32);
+                render_pass.draw_indexed(0..mesh.index_count, 0, 0..self.instance_count);
+            }
         }
-        
+
+        // Tonemap pass: ACES filmic from the HDR target into the visible sRGB swapchain.
+        hdr.render(&mut encoder, &view);
+
         queue.submit(std::iter::once(encoder.finish()));
         output.present();
-        
+
         self.frame_count += 1;
+        Ok(())
     }
     
     fn toggle_fullscreen(&mut self) {
@@ -497,16 +887,40 @@ impl ApplicationHandler for BhumiGpuApp {
             
             WindowEvent::RedrawRequested => {
                 let now = Instant::now();
-                let dt = (now - self.last_frame).as_secs_f32();
+                let frame_time = (now - self.last_frame).as_secs_f32().min(MAX_FRAME_TIME);
                 self.last_frame = now;
-                
-                // Handle input and update physics
+
+                // Sample input once per real frame, then step physics in fixed-size slices so
+                // simulation behavior is decoupled from the display's frame rate.
                 self.handle_input();
-                self.update_physics(dt);
-                
-                // Render with GPU
-                self.render();
-                
+                let pos_before = Vec3::from(self.physics.get_drone_position());
+                self.accumulator += frame_time;
+                while self.accumulator >= PHYSICS_DT {
+                    self.step_physics();
+                    self.accumulator -= PHYSICS_DT;
+                }
+
+                // Interpolate the drone's position across the leftover fraction of a step so
+                // rendering stays smooth between fixed updates; rotation still snaps to the
+                // latest step since the physics rotation type doesn't support blending here.
+                let alpha = (self.accumulator / PHYSICS_DT).clamp(0.0, 1.0);
+                let pos_after = Vec3::from(self.physics.get_drone_position());
+                let drone_pos: [f32; 3] = pos_before.lerp(pos_after, alpha).into();
+                let drone_rot = self.physics.get_drone_rotation();
+                self.camera.update(drone_pos, drone_rot, frame_time);
+
+                // Render with GPU, recovering gracefully from routine surface errors
+                match self.render() {
+                    Ok(()) => {}
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        info!("Surface out of memory - exiting");
+                        event_loop.exit();
+                    }
+                    Err(e) => {
+                        info!("Dropping frame: {:?}", e);
+                    }
+                }
+
                 // Request next frame
                 if let Some(window) = &self.window {
                     window.request_redraw();
@@ -514,11 +928,19 @@ impl ApplicationHandler for BhumiGpuApp {
             }
             
             WindowEvent::Resized(new_size) => {
-                if let (Some(surface), Some(device), Some(config)) = 
+                if let (Some(surface), Some(device), Some(config)) =
                     (&self.surface, &self.device, &mut self.config) {
                     config.width = new_size.width;
                     config.height = new_size.height;
                     surface.configure(device, config);
+
+                    let (depth_texture, depth_view) = create_depth_texture(device, config);
+                    self.depth_texture = Some(depth_texture);
+                    self.depth_view = Some(depth_view);
+
+                    if let Some(hdr) = &mut self.hdr {
+                        hdr.resize(device, config.width, config.height);
+                    }
                 }
             }
             
@@ -541,15 +963,29 @@ struct VertexInput {
     @location(1) color: vec3<f32>,
 }
 
+struct InstanceInput {
+    @location(2) model_matrix_0: vec4<f32>,
+    @location(3) model_matrix_1: vec4<f32>,
+    @location(4) model_matrix_2: vec4<f32>,
+    @location(5) model_matrix_3: vec4<f32>,
+}
+
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) color: vec3<f32>,
 }
 
 @vertex
-fn vs_main(vertex: VertexInput) -> VertexOutput {
+fn vs_main(vertex: VertexInput, instance: InstanceInput) -> VertexOutput {
+    let model_matrix = mat4x4<f32>(
+        instance.model_matrix_0,
+        instance.model_matrix_1,
+        instance.model_matrix_2,
+        instance.model_matrix_3,
+    );
+
     var out: VertexOutput;
-    out.clip_position = uniforms.view_proj_matrix * vec4<f32>(vertex.position, 1.0);
+    out.clip_position = uniforms.view_proj_matrix * model_matrix * vec4<f32>(vertex.position, 1.0);
     out.color = vertex.color;
     return out;
 }
@@ -560,16 +996,16 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
 }
 "#;
 
-fn main() {
-    env_logger::init();
-    
+/// Runs the full GPU 3D app (depth buffer, instancing, HDR tonemapping), selected from `main()`
+/// via the `--wgpu-3d` flag.
+pub fn run() {
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
-    
+
     let mut app = BhumiGpuApp::new();
-    
-    info!("ðŸš€ Starting Bhumi GUI with true GPU 3D rendering");
-    info!("ðŸŽ® Controls: WASD=fly, IJKL=rotate, 0=reset, 9=stop, F11=fullscreen");
-    
+
+    info!("🚀 Starting Bhumi GUI with true GPU 3D rendering");
+    info!("🎮 Controls: WASD=fly, IJKL=rotate, 0=reset, 9=stop, F11=fullscreen");
+
     event_loop.run_app(&mut app).unwrap();
 }
\ No newline at end of file
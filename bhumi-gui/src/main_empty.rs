@@ -1,7 +1,7 @@
 // Simple bhumi-gui with working GPU rendering
 use bhumi::{PhysicsWorld, Camera};
 use log::info;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::time::Instant;
 use winit::{
     application::ApplicationHandler,
@@ -12,26 +12,401 @@ use winit::{
     window::{Window, WindowId, Fullscreen},
 };
 
+use std::sync::Arc;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use winit::platform::web::{EventLoopExtWebSys, WindowAttributesExtWebSys};
+
 const RENDER_WIDTH: u32 = 320;
 const RENDER_HEIGHT: u32 = 240;
 
+/// Physics tick rate, independent of however fast the display can present frames.
+const FIXED_DT: f32 = 1.0 / 60.0;
+/// Cap how much simulation time a single real frame can queue up, so a stall (e.g. a window
+/// drag) doesn't force a burst of catch-up steps.
+const MAX_FRAME_TIME: f32 = 0.25;
+
+/// Fullscreen-triangle blit of the low-res render target, sampled with nearest-neighbor
+/// filtering so the upscale stays crisp/pixelated rather than blurry.
+const BLIT_SHADER: &str = r#"
+struct VertexOutput {
+    @builtin(position) clip_position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+    let x = f32((vertex_index << 1u) & 2u);
+    let y = f32(vertex_index & 2u);
+    out.uv = vec2<f32>(x, 1.0 - y);
+    out.clip_position = vec4<f32>(x * 2.0 - 1.0, 1.0 - y * 2.0, 0.0, 1.0);
+    return out;
+}
+
+@group(0) @binding(0) var low_res_texture: texture_2d<f32>;
+@group(0) @binding(1) var low_res_sampler: sampler;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return textureSample(low_res_texture, low_res_sampler, in.uv);
+}
+"#;
+
+/// A named action the drone/camera can react to, independent of which physical key drives it.
+///
+/// Continuous actions (thrust/rotation) are held down and resolved every frame in
+/// `handle_input`; discrete actions fire once on key-press in `window_event`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Action {
+    ThrustForward,
+    ThrustBackward,
+    ThrustLeft,
+    ThrustRight,
+    ThrustUp,
+    ThrustDown,
+    PitchUp,
+    PitchDown,
+    YawLeft,
+    YawRight,
+    RollLeft,
+    RollRight,
+    Reset,
+    GentleStop,
+    ToggleFullscreen,
+    ToggleCursorGrab,
+    Exit,
+}
+
+impl Action {
+    /// Whether this action is held-down-and-continuous vs. fired once on press.
+    fn is_continuous(self) -> bool {
+        !matches!(
+            self,
+            Action::Reset | Action::GentleStop | Action::ToggleFullscreen
+                | Action::ToggleCursorGrab | Action::Exit
+        )
+    }
+
+    /// Accumulate this action's contribution into the per-frame thrust/rotation deltas.
+    fn apply(self, thrust_force: &mut [f32; 3], rotation_delta: &mut [f32; 3]) {
+        match self {
+            Action::ThrustForward => thrust_force[2] += 0.3,
+            Action::ThrustBackward => thrust_force[2] -= 0.3,
+            Action::ThrustLeft => thrust_force[0] -= 0.3,
+            Action::ThrustRight => thrust_force[0] += 0.3,
+            Action::ThrustUp => thrust_force[1] += 0.5,
+            Action::ThrustDown => thrust_force[1] -= 0.5,
+            Action::PitchUp => rotation_delta[0] -= 0.02,
+            Action::PitchDown => rotation_delta[0] += 0.02,
+            Action::YawLeft => rotation_delta[1] -= 0.02,
+            Action::YawRight => rotation_delta[1] += 0.02,
+            Action::RollLeft => rotation_delta[2] -= 0.02,
+            Action::RollRight => rotation_delta[2] += 0.02,
+            _ => {}
+        }
+    }
+}
+
+/// Maps physical keys to named `Action`s, loaded with sensible defaults and overridable at
+/// runtime (e.g. from a future config file or in-game remapping menu).
+struct InputMap {
+    bindings: HashMap<KeyCode, Action>,
+}
+
+impl InputMap {
+    /// The WASD/Space/C + IJKL/UO scheme the simulator has always shipped with.
+    fn defaults() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(KeyCode::KeyW, Action::ThrustForward);
+        bindings.insert(KeyCode::KeyS, Action::ThrustBackward);
+        bindings.insert(KeyCode::KeyA, Action::ThrustLeft);
+        bindings.insert(KeyCode::KeyD, Action::ThrustRight);
+        bindings.insert(KeyCode::Space, Action::ThrustUp);
+        bindings.insert(KeyCode::KeyC, Action::ThrustDown);
+        bindings.insert(KeyCode::KeyI, Action::PitchUp);
+        bindings.insert(KeyCode::KeyK, Action::PitchDown);
+        bindings.insert(KeyCode::KeyJ, Action::YawLeft);
+        bindings.insert(KeyCode::KeyL, Action::YawRight);
+        bindings.insert(KeyCode::KeyU, Action::RollLeft);
+        bindings.insert(KeyCode::KeyO, Action::RollRight);
+        bindings.insert(KeyCode::Digit0, Action::Reset);
+        bindings.insert(KeyCode::Digit9, Action::GentleStop);
+        bindings.insert(KeyCode::F11, Action::ToggleFullscreen);
+        bindings.insert(KeyCode::Tab, Action::ToggleCursorGrab);
+        bindings.insert(KeyCode::Escape, Action::Exit);
+        bindings.insert(KeyCode::KeyQ, Action::Exit);
+        Self { bindings }
+    }
+
+    /// Override or add a single key's binding at runtime.
+    fn bind(&mut self, key: KeyCode, action: Action) {
+        self.bindings.insert(key, action);
+    }
+
+    fn action_for(&self, key: KeyCode) -> Option<Action> {
+        self.bindings.get(&key).copied()
+    }
+}
+
 struct BhumiGpuApp {
-    window: Option<Window>,
+    window: Option<Arc<Window>>,
     physics: PhysicsWorld,
     camera: Camera,
+    input_map: InputMap,
     keys_pressed: HashSet<KeyCode>,
     last_frame: Instant,
+    /// Leftover simulation time not yet consumed by a `FIXED_DT` physics step.
+    accumulator: f32,
     is_fullscreen: bool,
-    
+
     // Physics forces
     thrust_force: [f32; 3],
     rotation_delta: [f32; 3],
-    
+
+    // Mouse-look
+    mouse_delta: (f32, f32),
+    mouse_sensitivity: f32,
+    accumulated_pitch: f32,
+    cursor_grabbed: bool,
+
     // Simple GPU state for colored background
     device: Option<wgpu::Device>,
     queue: Option<wgpu::Queue>,
     surface: Option<wgpu::Surface<'static>>,
     config: Option<wgpu::SurfaceConfiguration>,
+
+    // Retro low-res render target, blitted up to the window with nearest-neighbor filtering
+    low_res_view: Option<wgpu::TextureView>,
+    blit_pipeline: Option<wgpu::RenderPipeline>,
+    blit_bind_group: Option<wgpu::BindGroup>,
+}
+
+/// Everything `build_gpu_resources` produces, bundled up so it can be handed off across an
+/// `await` point (native: built inline in `resumed` via `pollster::block_on`; web: sent through a
+/// `BhumiUserEvent::GpuReady` proxy event once the async adapter/device request resolves).
+struct GpuResources {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    surface: wgpu::Surface<'static>,
+    config: wgpu::SurfaceConfiguration,
+    low_res_view: wgpu::TextureView,
+    blit_pipeline: wgpu::RenderPipeline,
+    blit_bind_group: wgpu::BindGroup,
+}
+
+/// Event fed back into the event loop via `EventLoopProxy` once async GPU init completes.
+///
+/// Needed because `ApplicationHandler::resumed` can't block on a future on wasm32 (there's no
+/// thread to block); on native we can just `pollster::block_on` it instead and skip this path.
+enum BhumiUserEvent {
+    GpuReady(GpuResources),
+}
+
+impl BhumiGpuApp {
+    /// Create the wgpu instance/adapter/device/queue, configure the surface for `window`, and
+    /// build the low-res render target on top of it.
+    ///
+    /// Follows the standard learn-wgpu "Display" setup: request an adapter compatible with the
+    /// window's surface, request a device off of it, then configure the surface with the
+    /// preferred sRGB format reported by the surface's capabilities.
+    async fn build_gpu_resources(window: &Window) -> GpuResources {
+        let size = window.inner_size();
+
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
+            backends: wgpu::Backends::all(),
+            ..Default::default()
+        });
+
+        let surface = instance.create_surface(window).unwrap();
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: Some(&surface),
+                force_fallback_adapter: false,
+            })
+            .await
+            .unwrap();
+
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: None,
+                    required_features: wgpu::Features::empty(),
+                    required_limits: wgpu::Limits::default(),
+                    memory_hints: wgpu::MemoryHints::default(),
+                },
+                None,
+            )
+            .await
+            .unwrap();
+
+        let surface_caps = surface.get_capabilities(&adapter);
+        let surface_format = surface_caps
+            .formats
+            .iter()
+            .copied()
+            .find(|f| f.is_srgb())
+            .unwrap_or(surface_caps.formats[0]);
+
+        let config = wgpu::SurfaceConfiguration {
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            format: surface_format,
+            width: size.width,
+            height: size.height,
+            present_mode: surface_caps.present_modes[0],
+            alpha_mode: surface_caps.alpha_modes[0],
+            view_formats: vec![],
+            desired_maximum_frame_latency: 2,
+        };
+
+        surface.configure(&device, &config);
+
+        let (low_res_view, blit_pipeline, blit_bind_group) =
+            Self::create_low_res_target(&device, surface_format);
+
+        info!("GPU initialized: {}x{} @ {:?}", size.width, size.height, surface_format);
+
+        GpuResources {
+            device,
+            queue,
+            surface,
+            config,
+            low_res_view,
+            blit_pipeline,
+            blit_bind_group,
+        }
+    }
+
+    /// Move a completed `GpuResources` bundle into `self`'s fields.
+    fn apply_gpu_resources(&mut self, resources: GpuResources) {
+        self.device = Some(resources.device);
+        self.queue = Some(resources.queue);
+        self.surface = Some(resources.surface);
+        self.config = Some(resources.config);
+        self.low_res_view = Some(resources.low_res_view);
+        self.blit_pipeline = Some(resources.blit_pipeline);
+        self.blit_bind_group = Some(resources.blit_bind_group);
+    }
+
+    /// Build the offscreen `RENDER_WIDTH`×`RENDER_HEIGHT` render target plus the pipeline that
+    /// blits it to the swapchain with nearest-neighbor sampling, so the game renders at a fixed
+    /// retro resolution and is scaled up to fit the window afterwards (see `render`).
+    fn create_low_res_target(
+        device: &wgpu::Device,
+        surface_format: wgpu::TextureFormat,
+    ) -> (wgpu::TextureView, wgpu::RenderPipeline, wgpu::BindGroup) {
+        let low_res_texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("low_res_target"),
+            size: wgpu::Extent3d {
+                width: RENDER_WIDTH,
+                height: RENDER_HEIGHT,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: surface_format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let low_res_view = low_res_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let low_res_sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            label: Some("low_res_sampler"),
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("blit_bind_group_layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        let blit_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("blit_bind_group"),
+            layout: &bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&low_res_view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(&low_res_sampler),
+                },
+            ],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("blit_shader"),
+            source: wgpu::ShaderSource::Wgsl(BLIT_SHADER.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("blit_pipeline_layout"),
+            bind_group_layouts: &[&bind_group_layout],
+            push_constant_ranges: &[],
+        });
+
+        let blit_pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("blit_pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &shader,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &shader,
+                entry_point: Some("fs_main"),
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: surface_format,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview: None,
+            cache: None,
+        });
+
+        (low_res_view, blit_pipeline, blit_bind_group)
+    }
+
+    /// Reconfigure the surface at its current `config` size, e.g. after a resize or a
+    /// `SurfaceError::Lost`/`Outdated` frame.
+    fn reconfigure_surface(&self) {
+        if let (Some(surface), Some(device), Some(config)) =
+            (&self.surface, &self.device, &self.config)
+        {
+            surface.configure(device, config);
+        }
+    }
 }
 
 impl BhumiGpuApp {
@@ -40,15 +415,24 @@ impl BhumiGpuApp {
             window: None,
             physics: PhysicsWorld::new(),
             camera: Camera::new(),
+            input_map: InputMap::defaults(),
             keys_pressed: HashSet::new(),
             last_frame: Instant::now(),
+            accumulator: 0.0,
             is_fullscreen: false,
             thrust_force: [0.0, 0.0, 0.0],
             rotation_delta: [0.0, 0.0, 0.0],
+            mouse_delta: (0.0, 0.0),
+            mouse_sensitivity: 0.0025,
+            accumulated_pitch: 0.0,
+            cursor_grabbed: false,
             device: None,
             queue: None,
             surface: None,
             config: None,
+            low_res_view: None,
+            blit_pipeline: None,
+            blit_bind_group: None,
         }
     }
     
@@ -57,30 +441,50 @@ impl BhumiGpuApp {
         self.thrust_force = [0.0, 0.0, 0.0];
         self.rotation_delta = [0.0, 0.0, 0.0];
         
-        // Process held keys
+        // Process held keys through the resolved action table
         for key in &self.keys_pressed {
-            match key {
-                // Translation (WASD)
-                KeyCode::KeyW => self.thrust_force[2] += 0.3,  // Forward
-                KeyCode::KeyS => self.thrust_force[2] -= 0.3,  // Backward
-                KeyCode::KeyA => self.thrust_force[0] -= 0.3,  // Left
-                KeyCode::KeyD => self.thrust_force[0] += 0.3,  // Right
-                KeyCode::Space => self.thrust_force[1] += 0.5, // Up
-                KeyCode::KeyC => self.thrust_force[1] -= 0.5,  // Down
-                
-                // Rotation (IJKL)
-                KeyCode::KeyI => self.rotation_delta[0] -= 0.02, // Pitch up
-                KeyCode::KeyK => self.rotation_delta[0] += 0.02, // Pitch down  
-                KeyCode::KeyJ => self.rotation_delta[1] -= 0.02, // Yaw left
-                KeyCode::KeyL => self.rotation_delta[1] += 0.02, // Yaw right
-                KeyCode::KeyU => self.rotation_delta[2] -= 0.02, // Roll left
-                KeyCode::KeyO => self.rotation_delta[2] += 0.02, // Roll right
-                _ => {}
+            if let Some(action) = self.input_map.action_for(*key) {
+                action.apply(&mut self.thrust_force, &mut self.rotation_delta);
+            }
+        }
+
+        // Fold accumulated mouse motion into yaw/pitch, clamping pitch to avoid gimbal flip
+        let (dx, dy) = self.mouse_delta;
+        if dx != 0.0 || dy != 0.0 {
+            let yaw_delta = dx * self.mouse_sensitivity;
+            let mut pitch_delta = dy * self.mouse_sensitivity;
+            let max_pitch = std::f32::consts::FRAC_PI_2 - 0.01;
+            if self.accumulated_pitch + pitch_delta > max_pitch {
+                pitch_delta = max_pitch - self.accumulated_pitch;
+            } else if self.accumulated_pitch + pitch_delta < -max_pitch {
+                pitch_delta = -max_pitch - self.accumulated_pitch;
             }
+            self.accumulated_pitch += pitch_delta;
+            self.rotation_delta[1] += yaw_delta;
+            self.rotation_delta[0] += pitch_delta;
         }
+        self.mouse_delta = (0.0, 0.0);
     }
-    
-    fn update_physics(&mut self, dt: f32) {
+
+    /// Toggle cursor grab/hide, used to enter/exit mouse-look mode.
+    fn toggle_cursor_grab(&mut self) {
+        let Some(window) = &self.window else { return };
+        self.cursor_grabbed = !self.cursor_grabbed;
+        if self.cursor_grabbed {
+            let _ = window
+                .set_cursor_grab(winit::window::CursorGrabMode::Confined)
+                .or_else(|_| window.set_cursor_grab(winit::window::CursorGrabMode::Locked));
+            window.set_cursor_visible(false);
+        } else {
+            let _ = window.set_cursor_grab(winit::window::CursorGrabMode::None);
+            window.set_cursor_visible(true);
+        }
+    }
+
+    /// Advance the simulation by exactly `dt` (always `FIXED_DT`, see the `accumulator` loop in
+    /// `window_event`). Keeping this on a fixed tick means drone behavior doesn't change
+    /// with the display's frame rate.
+    fn step_physics(&mut self, dt: f32) {
         // Apply rotation
         if self.rotation_delta[0].abs() > 0.001 || self.rotation_delta[1].abs() > 0.001 || self.rotation_delta[2].abs() > 0.001 {
             let rapier_delta = rapier3d::prelude::Vector::new(
@@ -88,24 +492,109 @@ impl BhumiGpuApp {
             );
             self.physics.apply_rotation_delta(rapier_delta);
         }
-        
+
         // Apply thrust
         let rapier_thrust = rapier3d::prelude::Vector::new(
             self.thrust_force[0], self.thrust_force[1], self.thrust_force[2]
         );
         let drone_pos = self.physics.step(dt, rapier_thrust);
         let drone_rot = self.physics.get_drone_rotation();
-        
+
         // Update camera
-        self.camera.update(drone_pos, drone_rot);
+        self.camera.update(drone_pos, drone_rot, dt);
     }
-    
-    fn render(&mut self) {
-        // Basic test rendering - just clear to a color for now
-        if let Some(window) = &self.window {
-            // For now, just log that we're rendering
-            info!("Rendering frame (placeholder)");
+
+    /// Render one frame at the fixed `RENDER_WIDTH`×`RENDER_HEIGHT` retro resolution, then blit
+    /// it up to the window at an integer scale, letterboxing any leftover space so pixels stay
+    /// square instead of stretching to fill an arbitrary aspect ratio.
+    fn render(&mut self) -> Result<(), wgpu::SurfaceError> {
+        let (Some(device), Some(queue), Some(surface), Some(config), Some(low_res_view),
+            Some(blit_pipeline), Some(blit_bind_group)) = (
+            &self.device,
+            &self.queue,
+            &self.surface,
+            &self.config,
+            &self.low_res_view,
+            &self.blit_pipeline,
+            &self.blit_bind_group,
+        ) else {
+            return Ok(());
+        };
+
+        let output = surface.get_current_texture()?;
+        let view = output
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("render_encoder"),
+        });
+
+        {
+            let _render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("low_res_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: low_res_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color {
+                            r: 20.0 / 255.0,
+                            g: 20.0 / 255.0,
+                            b: 30.0 / 255.0,
+                            a: 1.0,
+                        }),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            // Drone/scene draw calls go here once a pipeline is wired up.
+        }
+
+        // Largest whole-number scale that fits the window, so each low-res pixel maps to an
+        // NxN block of real pixels instead of being smeared by bilinear/non-integer scaling.
+        let scale = (config.width / RENDER_WIDTH)
+            .min(config.height / RENDER_HEIGHT)
+            .max(1);
+        let scaled_width = (RENDER_WIDTH * scale).min(config.width);
+        let scaled_height = (RENDER_HEIGHT * scale).min(config.height);
+        let origin_x = (config.width - scaled_width) / 2;
+        let origin_y = (config.height - scaled_height) / 2;
+
+        {
+            let mut blit_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("blit_pass"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: wgpu::StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+                timestamp_writes: None,
+            });
+            blit_pass.set_pipeline(blit_pipeline);
+            blit_pass.set_bind_group(0, blit_bind_group, &[]);
+            blit_pass.set_viewport(
+                origin_x as f32,
+                origin_y as f32,
+                scaled_width as f32,
+                scaled_height as f32,
+                0.0,
+                1.0,
+            );
+            blit_pass.draw(0..3, 0..1);
         }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        output.present();
+
+        Ok(())
     }
     
     fn toggle_fullscreen(&mut self) {
@@ -122,32 +611,85 @@ impl BhumiGpuApp {
     }
 }
 
-impl ApplicationHandler for BhumiGpuApp {
+impl ApplicationHandler<BhumiUserEvent> for BhumiGpuApp {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
-        // Create window with adaptive scaling
-        let monitor = event_loop.primary_monitor().unwrap();
-        let monitor_size = monitor.size();
-        
-        // Scale to 80% of monitor size or at least 2x
-        let scale_x = (monitor_size.width * 8 / 10) / RENDER_WIDTH;
-        let scale_y = (monitor_size.height * 8 / 10) / RENDER_HEIGHT;
-        let scale = std::cmp::min(scale_x, scale_y).max(2);
-        
-        let window_size = PhysicalSize::new(RENDER_WIDTH * scale, RENDER_HEIGHT * scale);
-        
-        let window = event_loop.create_window(
-            Window::default_attributes()
-                .with_title("🚀 Bhumi 3D - GPU Flight Simulator")
-                .with_inner_size(window_size)
-        ).unwrap();
-        
-        info!("🎮 Bhumi GUI: {}×{} ({}x scale) on {}×{} monitor", 
-            window_size.width, window_size.height, scale, monitor_size.width, monitor_size.height);
-        info!("🎯 Controls: WASD=fly, IJKL=rotate, Q=quit, F11=fullscreen, 0=reset, 9=stop");
-        
+        // Native: size the window relative to the monitor. The web has no "monitor" to query
+        // (the canvas is sized by the page's CSS instead), so just pick a fixed 2x default.
+        #[cfg(not(target_arch = "wasm32"))]
+        let window_size = {
+            let monitor = event_loop.primary_monitor().unwrap();
+            let monitor_size = monitor.size();
+
+            // Scale to 80% of monitor size or at least 2x
+            let scale_x = (monitor_size.width * 8 / 10) / RENDER_WIDTH;
+            let scale_y = (monitor_size.height * 8 / 10) / RENDER_HEIGHT;
+            let scale = std::cmp::min(scale_x, scale_y).max(2);
+
+            info!("🎮 Bhumi GUI: {}x scale on {}×{} monitor", scale, monitor_size.width, monitor_size.height);
+            PhysicalSize::new(RENDER_WIDTH * scale, RENDER_HEIGHT * scale)
+        };
+        #[cfg(target_arch = "wasm32")]
+        let window_size = PhysicalSize::new(RENDER_WIDTH * 2, RENDER_HEIGHT * 2);
+
+        let mut window_attributes = Window::default_attributes()
+            .with_title("🚀 Bhumi 3D - GPU Flight Simulator")
+            .with_inner_size(window_size);
+        // Append the window's canvas into the page's <body> instead of expecting a
+        // pre-existing element to attach to.
+        #[cfg(target_arch = "wasm32")]
+        {
+            window_attributes = window_attributes.with_append(true);
+        }
+
+        let window = Arc::new(event_loop.create_window(window_attributes).unwrap());
+
+        info!("🎯 Controls: WASD=fly, IJKL=rotate, mouse-look=Tab to grab, Q=quit, F11=fullscreen, 0=reset, 9=stop");
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let resources = pollster::block_on(Self::build_gpu_resources(&window));
+            self.apply_gpu_resources(resources);
+        }
+
+        // `resumed` can't block on the adapter/device request on wasm32 (no thread to park),
+        // so kick off the async init and let it deliver its result back through a user event
+        // once the JS promises resolve.
+        #[cfg(target_arch = "wasm32")]
+        {
+            let proxy = event_loop.create_proxy();
+            let window_for_init = window.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                let resources = BhumiGpuApp::build_gpu_resources(&window_for_init).await;
+                let _ = proxy.send_event(BhumiUserEvent::GpuReady(resources));
+            });
+        }
+
         self.window = Some(window);
     }
 
+    fn user_event(&mut self, _event_loop: &ActiveEventLoop, event: BhumiUserEvent) {
+        match event {
+            BhumiUserEvent::GpuReady(resources) => {
+                info!("GPU ready (async init completed)");
+                self.apply_gpu_resources(resources);
+            }
+        }
+    }
+
+    fn device_event(
+        &mut self,
+        _event_loop: &ActiveEventLoop,
+        _device_id: winit::event::DeviceId,
+        event: winit::event::DeviceEvent,
+    ) {
+        if let winit::event::DeviceEvent::MouseMotion { delta } = event {
+            if self.cursor_grabbed {
+                self.mouse_delta.0 += delta.0 as f32;
+                self.mouse_delta.1 += delta.1 as f32;
+            }
+        }
+    }
+
     fn window_event(&mut self, event_loop: &ActiveEventLoop, _window_id: WindowId, event: WindowEvent) {
         match event {
             WindowEvent::CloseRequested => {
@@ -165,23 +707,25 @@ impl ApplicationHandler for BhumiGpuApp {
             } => {
                 match state {
                     ElementState::Pressed => {
-                        match key_code {
-                            KeyCode::Escape | KeyCode::KeyQ => {
+                        match self.input_map.action_for(key_code) {
+                            Some(Action::Exit) => {
                                 info!("Exit requested");
                                 event_loop.exit();
-                            },
-                            KeyCode::F11 => self.toggle_fullscreen(),
-                            KeyCode::Digit0 => {
+                            }
+                            Some(Action::ToggleFullscreen) => self.toggle_fullscreen(),
+                            Some(Action::ToggleCursorGrab) => self.toggle_cursor_grab(),
+                            Some(Action::Reset) => {
                                 info!("🔄 Reset drone");
                                 self.physics.reset_drone();
-                            },
-                            KeyCode::Digit9 => {
+                            }
+                            Some(Action::GentleStop) => {
                                 info!("🛑 Gentle stop");
                                 self.physics.gentle_stop();
-                            },
-                            _ => {
+                            }
+                            Some(action) if action.is_continuous() => {
                                 self.keys_pressed.insert(key_code);
                             }
+                            _ => {}
                         }
                     }
                     ElementState::Released => {
@@ -192,22 +736,50 @@ impl ApplicationHandler for BhumiGpuApp {
             
             WindowEvent::RedrawRequested => {
                 let now = Instant::now();
-                let dt = (now - self.last_frame).as_secs_f32();
+                let frame_time = (now - self.last_frame).as_secs_f32();
                 self.last_frame = now;
-                
-                // Update simulation
+
+                // Sample input once per real frame...
                 self.handle_input();
-                self.update_physics(dt);
-                self.render();
-                
+
+                // ...but step physics on a fixed tick, possibly multiple times per frame (or
+                // zero, if the frame arrived early), so simulation behavior is frame-rate
+                // independent.
+                self.accumulator += frame_time.min(MAX_FRAME_TIME);
+                while self.accumulator >= FIXED_DT {
+                    self.step_physics(FIXED_DT);
+                    self.accumulator -= FIXED_DT;
+                }
+
+                match self.render() {
+                    Ok(_) => {}
+                    Err(wgpu::SurfaceError::Lost | wgpu::SurfaceError::Outdated) => {
+                        self.reconfigure_surface();
+                    }
+                    Err(wgpu::SurfaceError::OutOfMemory) => {
+                        log::error!("GPU out of memory, exiting");
+                        event_loop.exit();
+                    }
+                    Err(wgpu::SurfaceError::Timeout) => {
+                        log::warn!("Surface frame timed out");
+                    }
+                }
+
                 // Set consistent 60 FPS timing for next frame
                 event_loop.set_control_flow(ControlFlow::WaitUntil(
                     std::time::Instant::now() + std::time::Duration::from_millis(16)
                 ));
             }
-            
+
             WindowEvent::Resized(new_size) => {
                 info!("Window resized: {}×{}", new_size.width, new_size.height);
+                if let Some(config) = &mut self.config {
+                    if new_size.width > 0 && new_size.height > 0 {
+                        config.width = new_size.width;
+                        config.height = new_size.height;
+                        self.reconfigure_surface();
+                    }
+                }
             }
             
             _ => {}
@@ -215,20 +787,42 @@ impl ApplicationHandler for BhumiGpuApp {
     }
 }
 
-fn main() {
-    env_logger::init();
-    
-    let event_loop = EventLoop::new().unwrap();
+/// Runs the wgpu-backed GUI, selected from `main()` via the `--wgpu` flag. Unlike the default
+/// `pixels`-based app this crate ships with, this one owns its own swapchain/render-pass setup
+/// (see `BhumiGpuApp::render` above), so it's useful as a testbed for GPU-side features that
+/// `pixels` can't express.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn run() {
+    let event_loop = EventLoop::<BhumiUserEvent>::with_user_event().build().unwrap();
     // Set fixed 60 FPS timing
     event_loop.set_control_flow(ControlFlow::WaitUntil(
         std::time::Instant::now() + std::time::Duration::from_millis(16)
     ));
-    
+
     let mut app = BhumiGpuApp::new();
-    
+
     info!("🚀 Bhumi GUI starting...");
-    
+
     if let Err(e) = event_loop.run_app(&mut app) {
         log::error!("Event loop error: {}", e);
     }
+}
+
+/// wasm32 entry point, invoked by the JS glue as soon as the module loads.
+///
+/// There's no blocking `run_app` on the web: the browser owns the event loop, so we hand our
+/// `ApplicationHandler` over to it with `spawn_app` and return immediately. Frames are then paced
+/// by the same 16ms `WaitUntil` deadline `window_event` sets after each redraw on native.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen(start)]
+pub fn main_wasm() {
+    console_error_panic_hook::set_once();
+    console_log::init_with_level(log::Level::Info).expect("Failed to init logger");
+
+    let event_loop = EventLoop::<BhumiUserEvent>::with_user_event().build().unwrap();
+    let app = BhumiGpuApp::new();
+
+    info!("🚀 Bhumi GUI starting (wasm)...");
+
+    event_loop.spawn_app(app);
 }
\ No newline at end of file
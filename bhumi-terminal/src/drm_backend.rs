@@ -0,0 +1,226 @@
+// Direct DRM/KMS framebuffer backend - scans the PixelBuffer out to a Linux console with no
+// X/Wayland/terminal in the way, for kiosk/embedded use. Lives behind the `drm` cargo feature
+// since it pulls in libdrm/libgbm system dependencies that most builds don't need.
+use std::fs::{File, OpenOptions};
+use std::io::Result;
+use std::os::unix::io::AsFd;
+
+use bhumi::{InputEvent, PixelBuffer, PixelRenderer, Renderer};
+use drm::control::{connector, crtc, framebuffer, Device as ControlDevice, Mode};
+use drm::Device as BasicDevice;
+use gbm::{BufferObjectFlags, Device as GbmDevice, Format as GbmFormat};
+
+const DRM_CARD_PATH: &str = "/dev/dri/card0";
+
+/// Minimal wrapper so `drm`/`gbm` can treat an open `/dev/dri/cardN` fd as a device handle.
+struct Card(File);
+
+impl AsFd for Card {
+    fn as_fd(&self) -> std::os::unix::io::BorrowedFd<'_> {
+        self.0.as_fd()
+    }
+}
+
+impl BasicDevice for Card {}
+impl ControlDevice for Card {}
+
+/// Scans the shared `PixelBuffer` out to a KMS framebuffer backed by a GBM buffer object, setting
+/// the CRTC to a fresh framebuffer once per `render_frame`. Input comes from evdev rather than a
+/// window system.
+pub struct DrmRenderer {
+    gbm: GbmDevice<Card>,
+    connector: connector::Handle,
+    crtc: crtc::Handle,
+    mode: Mode,
+    current_fb: Option<framebuffer::Handle>,
+    input: evdev_input::EvdevInput,
+    should_exit: bool,
+}
+
+impl PixelRenderer for DrmRenderer {
+    fn new() -> Self {
+        let card = Card(
+            OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(DRM_CARD_PATH)
+                .expect("failed to open DRM device - is /dev/dri/card0 present and accessible?"),
+        );
+        let gbm = GbmDevice::new(card).expect("failed to create GBM device from DRM card");
+
+        let resources = gbm
+            .as_ref()
+            .resource_handles()
+            .expect("failed to fetch DRM resource handles");
+
+        // Pick the first connected connector, its preferred mode, and the CRTC driving it.
+        let connector_info = resources
+            .connectors()
+            .iter()
+            .filter_map(|&handle| gbm.as_ref().get_connector(handle, false).ok())
+            .find(|info| info.state() == connector::State::Connected)
+            .expect("no connected DRM connector found");
+        let connector = connector_info.handle();
+        let mode = *connector_info
+            .modes()
+            .first()
+            .expect("connected connector has no modes");
+        let encoder = connector_info
+            .current_encoder()
+            .and_then(|handle| gbm.as_ref().get_encoder(handle).ok())
+            .expect("connector has no current encoder");
+        let crtc = encoder
+            .crtc()
+            .expect("encoder has no attached CRTC");
+
+        Self {
+            gbm,
+            connector,
+            crtc,
+            mode,
+            current_fb: None,
+            input: evdev_input::EvdevInput::new(),
+            should_exit: false,
+        }
+    }
+
+    fn render_frame(&mut self, buffer: &PixelBuffer) -> Result<()> {
+        let (width, height) = self.mode.size();
+
+        // Allocate a GBM buffer object sized to the mode, and nearest-neighbor scale/copy the
+        // drone-view PixelBuffer into it (the view is almost always smaller than the display).
+        let mut bo = self
+            .gbm
+            .create_buffer_object::<()>(
+                width as u32,
+                height as u32,
+                GbmFormat::Xrgb8888,
+                BufferObjectFlags::SCANOUT | BufferObjectFlags::WRITE,
+            )
+            .expect("failed to allocate GBM buffer object");
+
+        let mut argb = vec![0u8; width as usize * height as usize * 4];
+        for y in 0..height as u32 {
+            let src_y = y * buffer.height / height as u32;
+            for x in 0..width as u32 {
+                let src_x = x * buffer.width / width as u32;
+                if let Some(pixel) = buffer.get_pixel(src_x, src_y) {
+                    let idx = (y as usize * width as usize + x as usize) * 4;
+                    argb[idx] = pixel[2]; // B
+                    argb[idx + 1] = pixel[1]; // G
+                    argb[idx + 2] = pixel[0]; // R
+                    argb[idx + 3] = 0xff;
+                }
+            }
+        }
+        bo.write(&argb).expect("failed to upload frame into GBM buffer object");
+
+        let fb = self
+            .gbm
+            .as_ref()
+            .add_framebuffer(&bo, 32, 32)
+            .expect("failed to create DRM framebuffer from GBM buffer object");
+
+        self.gbm
+            .as_ref()
+            .set_crtc(self.crtc, Some(fb), (0, 0), &[self.connector], Some(self.mode))
+            .expect("failed to set CRTC to new framebuffer");
+
+        if let Some(old_fb) = self.current_fb.replace(fb) {
+            let _ = self.gbm.as_ref().destroy_framebuffer(old_fb);
+        }
+
+        Ok(())
+    }
+
+    fn handle_input(&mut self) -> Vec<InputEvent> {
+        let (events, exit) = self.input.poll();
+        if exit {
+            self.should_exit = true;
+        }
+        events
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+}
+
+/// evdev/libinput translation, split out so the keycode-to-`InputEvent` mapping reads the same
+/// as the terminal and minifb backends' own input handling.
+mod evdev_input {
+    use bhumi::InputEvent;
+    use evdev::{Device, InputEventKind, Key};
+
+    /// Scans `/dev/input/event*` once at startup for the first device that looks like a
+    /// keyboard; a kiosk box has exactly one, so this deliberately doesn't try to be clever.
+    pub struct EvdevInput {
+        device: Option<Device>,
+    }
+
+    impl EvdevInput {
+        pub fn new() -> Self {
+            let device = evdev::enumerate()
+                .map(|(_, device)| device)
+                .find(|device| device.supported_keys().is_some());
+            Self { device }
+        }
+
+        pub fn poll(&mut self) -> (Vec<InputEvent>, bool) {
+            let mut events = Vec::new();
+            let mut exit = false;
+            let Some(device) = self.device.as_mut() else {
+                return (events, exit);
+            };
+            let Ok(fetched) = device.fetch_events() else {
+                return (events, exit);
+            };
+            for event in fetched {
+                if let InputEventKind::Key(key) = event.kind() {
+                    if event.value() == 0 {
+                        continue; // key release
+                    }
+                    match key {
+                        Key::KEY_W | Key::KEY_UP => events.push(InputEvent::ThrustForward),
+                        Key::KEY_S | Key::KEY_DOWN => events.push(InputEvent::ThrustBackward),
+                        Key::KEY_A | Key::KEY_LEFT => events.push(InputEvent::ThrustLeft),
+                        Key::KEY_D | Key::KEY_RIGHT => events.push(InputEvent::ThrustRight),
+                        Key::KEY_SPACE => events.push(InputEvent::ThrustUp),
+                        Key::KEY_C => events.push(InputEvent::ThrustDown),
+                        Key::KEY_0 => events.push(InputEvent::Reset),
+                        Key::KEY_9 => events.push(InputEvent::Stop),
+                        Key::KEY_ESC | Key::KEY_Q => exit = true,
+                        _ => {}
+                    }
+                }
+            }
+            if exit {
+                events.push(InputEvent::Exit);
+            }
+            (events, exit)
+        }
+    }
+}
+
+/// Drives the DRM backend standalone, same loop shape as the minifb backend's `run`.
+pub fn run() -> Result<()> {
+    let mut drm_renderer = DrmRenderer::new();
+    let mut core_renderer = Renderer::new();
+
+    let mut last_instant = std::time::Instant::now();
+
+    while !drm_renderer.should_exit() {
+        let now = std::time::Instant::now();
+        let dt = (now - last_instant).as_secs_f32();
+        last_instant = now;
+
+        let input_events = drm_renderer.handle_input();
+
+        core_renderer.update(dt, &input_events);
+        core_renderer.render();
+
+        drm_renderer.render_frame(&core_renderer.buffer)?;
+    }
+
+    Ok(())
+}
@@ -11,6 +11,10 @@ use std::fs::OpenOptions;
 use bhumi::{PixelRenderer, PixelBuffer, Renderer, InputEvent};
 use image::{RgbaImage, DynamicImage};
 
+#[cfg(feature = "drm")]
+mod drm_backend;
+mod main_minifb;
+
 /// Terminal renderer using viuer for high-quality image display
 struct TerminalRenderer {
     should_exit: bool,
@@ -19,13 +23,808 @@ struct TerminalRenderer {
     log_file: std::fs::File,
     frame_count: u32,
     render_mode: ViuerMode,
+    /// Whether we've done the one-time full-screen clear for the current physics-display run;
+    /// after that, the Kitty/Block paths redraw in place instead of wiping the whole terminal.
+    cleared_once: bool,
+    block_grid: block::CellGrid,
+    keymap: keymap::Keymap,
+    /// Rewind direction requested by the last `handle_input` call (-1 = back, +1 = forward), if
+    /// any - consumed once per main-loop iteration since the actual scrub is driven from outside
+    /// the backend via `rewind::Recorder`.
+    pending_rewind: Option<i32>,
 }
 
+/// Path the keymap config is loaded from; created by the user, not shipped by default.
+const KEYMAP_CONFIG_PATH: &str = "bhumi_keymap.cfg";
+
 #[derive(Debug, Clone, Copy)]
 enum ViuerMode {
-    Auto,        // Let viuer auto-detect best protocol  
+    Auto,        // Let viuer auto-detect best protocol
     Block,       // Force block characters with truecolor
     LowRes,      // Smaller image for different look
+    Kitty,       // Our own Kitty graphics protocol encoder, bypassing viuer entirely
+}
+
+/// Standalone encoder for the Kitty terminal graphics protocol
+/// (https://sw.kovidgoyal.net/kitty/graphics-protocol/), used instead of viuer when
+/// `ViuerMode::Kitty` is selected so we're not at the mercy of viuer's own Kitty support (or
+/// lack of chunking/transmission options we want control over).
+mod kitty {
+    use std::io::{self, Write};
+
+    const CHUNK_SIZE: usize = 4096;
+
+    /// Base64-encode `data` using the standard alphabet (no external crate).
+    fn base64_encode(data: &[u8]) -> String {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+        let mut out = String::with_capacity((data.len() + 2) / 3 * 4);
+        for chunk in data.chunks(3) {
+            let b0 = chunk[0];
+            let b1 = *chunk.get(1).unwrap_or(&0);
+            let b2 = *chunk.get(2).unwrap_or(&0);
+
+            out.push(ALPHABET[(b0 >> 2) as usize] as char);
+            out.push(ALPHABET[(((b0 & 0x03) << 4) | (b1 >> 4)) as usize] as char);
+            out.push(if chunk.len() > 1 {
+                ALPHABET[(((b1 & 0x0f) << 2) | (b2 >> 6)) as usize] as char
+            } else {
+                '='
+            });
+            out.push(if chunk.len() > 2 {
+                ALPHABET[(b2 & 0x3f) as usize] as char
+            } else {
+                '='
+            });
+        }
+        out
+    }
+
+    /// Write one raw RGBA `width`×`height` image to `out` as a Kitty graphics APC sequence,
+    /// chunked to `CHUNK_SIZE` base64 bytes per escape as the protocol requires for large
+    /// payloads.
+    pub fn write_image(out: &mut impl Write, width: u32, height: u32, rgba: &[u8]) -> io::Result<()> {
+        let encoded = base64_encode(rgba);
+        let mut chunks = encoded.as_bytes().chunks(CHUNK_SIZE).peekable();
+
+        let Some(first) = chunks.next() else {
+            return Ok(());
+        };
+        let more = if chunks.peek().is_some() { 1 } else { 0 };
+
+        // f=32: raw RGBA pixel data; a=T: transmit and display. `t` is left out, so the payload
+        // is read as direct data (the protocol's default, t=d).
+        write!(
+            out,
+            "\x1b_Ga=T,f=32,s={},v={},m={};{}\x1b\\",
+            width,
+            height,
+            more,
+            std::str::from_utf8(first).unwrap()
+        )?;
+
+        while let Some(chunk) = chunks.next() {
+            let more = if chunks.peek().is_some() { 1 } else { 0 };
+            write!(out, "\x1b_Gm={};{}\x1b\\", more, std::str::from_utf8(chunk).unwrap())?;
+        }
+
+        out.flush()
+    }
+}
+
+/// Remappable keyboard bindings, loaded from a plain-text config file (`key = action` per
+/// line) and falling back to the built-in defaults for anything the file doesn't override or
+/// if the file doesn't exist at all.
+mod keymap {
+    use crossterm::event::KeyCode;
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Action {
+        ThrustForward,
+        ThrustBackward,
+        ThrustLeft,
+        ThrustRight,
+        ThrustUp,
+        ThrustDown,
+        CycleRenderMode,
+        Reset,
+        Stop,
+        Exit,
+        /// Scrubs backward through `rewind::Recorder`'s history by `rewind::STEP_FRAMES`.
+        RewindBack,
+        /// Scrubs forward by `rewind::STEP_FRAMES`, capped at `rewind::Recorder::live_frame`.
+        /// Every rewind drops the history past the frame it lands on, so this never moves past
+        /// the current frame.
+        RewindForward,
+    }
+
+    impl Action {
+        fn parse(name: &str) -> Option<Action> {
+            Some(match name {
+                "thrust_forward" => Action::ThrustForward,
+                "thrust_backward" => Action::ThrustBackward,
+                "thrust_left" => Action::ThrustLeft,
+                "thrust_right" => Action::ThrustRight,
+                "thrust_up" => Action::ThrustUp,
+                "thrust_down" => Action::ThrustDown,
+                "cycle_render_mode" => Action::CycleRenderMode,
+                "reset" => Action::Reset,
+                "stop" => Action::Stop,
+                "exit" => Action::Exit,
+                "rewind_back" => Action::RewindBack,
+                "rewind_forward" => Action::RewindForward,
+                _ => return None,
+            })
+        }
+    }
+
+    pub struct Keymap {
+        bindings: HashMap<String, Action>,
+    }
+
+    impl Keymap {
+        /// The WASD/arrows + Tab/0/9/q/Esc scheme the terminal renderer has always shipped with.
+        pub fn defaults() -> Self {
+            let mut bindings = HashMap::new();
+            bindings.insert("w".into(), Action::ThrustForward);
+            bindings.insert("up".into(), Action::ThrustForward);
+            bindings.insert("s".into(), Action::ThrustBackward);
+            bindings.insert("down".into(), Action::ThrustBackward);
+            bindings.insert("a".into(), Action::ThrustLeft);
+            bindings.insert("left".into(), Action::ThrustLeft);
+            bindings.insert("d".into(), Action::ThrustRight);
+            bindings.insert("right".into(), Action::ThrustRight);
+            bindings.insert("space".into(), Action::ThrustUp);
+            bindings.insert("c".into(), Action::ThrustDown);
+            bindings.insert("tab".into(), Action::CycleRenderMode);
+            bindings.insert("0".into(), Action::Reset);
+            bindings.insert("9".into(), Action::Stop);
+            bindings.insert("q".into(), Action::Exit);
+            bindings.insert("esc".into(), Action::Exit);
+            bindings.insert("[".into(), Action::RewindBack);
+            bindings.insert("]".into(), Action::RewindForward);
+            Self { bindings }
+        }
+
+        /// Load `path`, layering any `key = action` overrides it contains on top of the
+        /// defaults. Missing/unreadable files just mean "use the defaults".
+        pub fn load_or_default(path: &str) -> Self {
+            let mut keymap = Self::defaults();
+            if let Ok(contents) = std::fs::read_to_string(path) {
+                for line in contents.lines() {
+                    let line = line.split('#').next().unwrap_or("").trim();
+                    if line.is_empty() {
+                        continue;
+                    }
+                    let Some((key, action)) = line.split_once('=') else { continue };
+                    let Some(action) = Action::parse(action.trim()) else { continue };
+                    keymap.bindings.insert(key.trim().to_lowercase(), action);
+                }
+            }
+            keymap
+        }
+
+        pub fn action_for(&self, key: KeyCode) -> Option<Action> {
+            self.bindings.get(&Self::key_name(key)?).copied()
+        }
+
+        /// Canonical lowercase name used as the config-file key for this `KeyCode`, or `None`
+        /// for keys that aren't bindable (e.g. modifiers reported on their own).
+        fn key_name(key: KeyCode) -> Option<String> {
+            Some(match key {
+                KeyCode::Char(' ') => "space".into(),
+                KeyCode::Char(c) => c.to_ascii_lowercase().to_string(),
+                KeyCode::Up => "up".into(),
+                KeyCode::Down => "down".into(),
+                KeyCode::Left => "left".into(),
+                KeyCode::Right => "right".into(),
+                KeyCode::Tab => "tab".into(),
+                KeyCode::Esc => "esc".into(),
+                KeyCode::Enter => "enter".into(),
+                _ => return None,
+            })
+        }
+    }
+}
+
+/// Rhai-scripted autopilot: an alternative to live keyboard input that computes each frame's
+/// thrust from the drone's own position/velocity/elapsed sim time, mirroring how
+/// `bhumi::scene_script` embeds `rhai` for scene logic. Compiled once before the loop rather than
+/// re-parsed every frame.
+mod autopilot {
+    use bhumi::InputEvent;
+
+    /// Largest thrust magnitude a script can apply in one frame - keeps a runaway autopilot
+    /// script from flinging the drone with an arbitrarily large force.
+    const MAX_FORCE: f32 = 0.5;
+
+    pub struct ScriptController {
+        engine: rhai::Engine,
+        ast: rhai::AST,
+        scope: rhai::Scope<'static>,
+        /// Last script error, if any - logged once by the caller rather than every frame until a
+        /// subsequent call succeeds.
+        last_error: Option<String>,
+    }
+
+    impl ScriptController {
+        pub fn compile(path: &str) -> Result<Self, Box<rhai::EvalAltResult>> {
+            let engine = rhai::Engine::new();
+            let ast = engine.compile_file(path.into())?;
+            Ok(Self {
+                engine,
+                ast,
+                scope: rhai::Scope::new(),
+                last_error: None,
+            })
+        }
+
+        /// Calls the script's `control(x, y, z, vx, vy, vz, t)`, returning the result as an
+        /// `InputEvent::Thrust` clamped to `MAX_FORCE`. Returns `None` (recording the error,
+        /// readable via `last_error`) on failure, so a bad autopilot script just leaves the drone
+        /// uncontrolled that frame instead of aborting the run.
+        pub fn thrust_event(&mut self, pos: [f32; 3], vel: [f32; 3], t: f32) -> Option<InputEvent> {
+            let result = self.engine.call_fn::<rhai::Array>(
+                &mut self.scope,
+                &self.ast,
+                "control",
+                (pos[0], pos[1], pos[2], vel[0], vel[1], vel[2], t),
+            );
+            match result {
+                Ok(array) if array.len() == 3 => {
+                    self.last_error = None;
+                    let x = array[0].as_float().unwrap_or(0.0) as f32;
+                    let y = array[1].as_float().unwrap_or(0.0) as f32;
+                    let z = array[2].as_float().unwrap_or(0.0) as f32;
+                    let magnitude = (x * x + y * y + z * z).sqrt();
+                    let (x, y, z) = if magnitude > MAX_FORCE && magnitude > 0.0 {
+                        let scale = MAX_FORCE / magnitude;
+                        (x * scale, y * scale, z * scale)
+                    } else {
+                        (x, y, z)
+                    };
+                    Some(InputEvent::Thrust { x, y, z })
+                }
+                Ok(_) => {
+                    self.last_error = Some("control() must return a 3-element array".to_string());
+                    None
+                }
+                Err(err) => {
+                    self.last_error = Some(err.to_string());
+                    None
+                }
+            }
+        }
+
+        pub fn last_error(&self) -> Option<&str> {
+            self.last_error.as_deref()
+        }
+    }
+}
+
+/// Deterministic rewind/scrub through recent flight: a ring buffer of per-frame inputs plus
+/// periodic `PhysicsWorld::save_state` keyframes, replayed back through the same
+/// `Renderer::update` the live loop calls. `[` scrubs backward and flight continues live from
+/// wherever the scrub lands - mirrors `replay`'s on-disk log, just kept in memory and scrubbable
+/// instead of written out and played back start-to-finish. `]` is capped at the newest recorded
+/// frame; since each rewind drops the history after its landing point, it never moves forward.
+mod rewind {
+    use bhumi::{InputEvent, Renderer};
+    use std::collections::VecDeque;
+
+    /// How many frames a single `[`/`]` press rewinds or fast-forwards.
+    pub const STEP_FRAMES: usize = 30; // 0.5s at 60Hz
+    /// Capture a keyframe every this-many frames.
+    const KEYFRAME_INTERVAL: usize = 15;
+    /// How many frames of input/keyframe history to retain before the oldest is dropped.
+    const HISTORY_FRAMES: usize = 3600; // 60s at 60Hz
+
+    struct Keyframe {
+        frame: usize,
+        state: Vec<u8>,
+    }
+
+    pub struct Recorder {
+        /// Frame number of `inputs[0]` - frames older than this have scrolled out of history.
+        base_frame: usize,
+        inputs: VecDeque<(f32, Vec<InputEvent>)>,
+        keyframes: VecDeque<Keyframe>,
+        frame: usize,
+    }
+
+    impl Recorder {
+        pub fn new() -> Self {
+            Self {
+                base_frame: 0,
+                inputs: VecDeque::new(),
+                keyframes: VecDeque::new(),
+                frame: 0,
+            }
+        }
+
+        pub fn frame(&self) -> usize {
+            self.frame
+        }
+
+        /// Call once per frame, right after `renderer.update(dt, events)` advanced the sim.
+        pub fn record(&mut self, renderer: &Renderer, dt: f32, events: &[InputEvent]) {
+            self.inputs.push_back((dt, events.to_vec()));
+            while self.inputs.len() > HISTORY_FRAMES {
+                self.inputs.pop_front();
+                self.base_frame += 1;
+            }
+
+            self.frame += 1;
+            if self.frame % KEYFRAME_INTERVAL == 0 {
+                self.keyframes.push_back(Keyframe {
+                    frame: self.frame,
+                    state: renderer.physics.save_state(),
+                });
+                let max_keyframes = HISTORY_FRAMES / KEYFRAME_INTERVAL + 1;
+                while self.keyframes.len() > max_keyframes {
+                    self.keyframes.pop_front();
+                }
+            }
+        }
+
+        /// The most recent frame still covered by recorded input - scrubbing forward cannot pass
+        /// this.
+        pub fn live_frame(&self) -> usize {
+            self.base_frame + self.inputs.len()
+        }
+
+        fn keyframe_state_at_or_before(&self, target_frame: usize) -> Option<(usize, Vec<u8>)> {
+            self.keyframes
+                .iter()
+                .rev()
+                .find(|k| k.frame <= target_frame)
+                .map(|k| (k.frame, k.state.clone()))
+        }
+
+        /// Scrubs `renderer` to `target_frame`: restores the nearest earlier keyframe, then
+        /// replays the recorded `(dt, events)` pairs from there back up to `target_frame` through
+        /// the same `Renderer::update` the live loop calls. Does nothing if no keyframe old
+        /// enough has been recorded yet.
+        pub fn rewind(&mut self, renderer: &mut Renderer, target_frame: usize) {
+            let Some((keyframe_frame, state)) = self.keyframe_state_at_or_before(target_frame)
+            else {
+                return;
+            };
+            renderer.physics.restore_state(&state);
+            self.frame = keyframe_frame;
+
+            for frame in keyframe_frame..target_frame {
+                let Some((dt, events)) = self.inputs.get(frame - self.base_frame).cloned() else {
+                    break;
+                };
+                renderer.update(dt, &events);
+                self.frame += 1;
+            }
+
+            // Drop input/keyframe history recorded past the point we landed on, so the next
+            // `record()` appends right where this scrub ended instead of stacking new frames on
+            // top of the stale post-rewind timeline (which would desync `inputs`' indexing from
+            // `frame - base_frame` on a later rewind).
+            self.inputs.truncate(self.frame - self.base_frame);
+            self.keyframes.retain(|k| k.frame <= self.frame);
+        }
+    }
+}
+
+/// Counts drone "bounces" off the room's walls/floor/ceiling: a reversal in velocity sign on any
+/// axis large enough to be a wall restitution bounce rather than just damping settling, since the
+/// shared `PhysicsWorld` drives Rapier's pipeline with no collision event handler of its own to
+/// read a real contact from.
+mod bounce {
+    /// Velocity magnitude (m/s) an axis must clear, both before and after a sign flip, to count
+    /// as a bounce - keeps damping noise near zero from registering as one.
+    const BOUNCE_THRESHOLD: f32 = 0.05;
+
+    pub struct Counter {
+        prev_velocity: [f32; 3],
+        pub count: u32,
+    }
+
+    impl Counter {
+        pub fn new() -> Self {
+            Self {
+                prev_velocity: [0.0; 3],
+                count: 0,
+            }
+        }
+
+        /// Call once per frame with the drone's current velocity.
+        pub fn update(&mut self, velocity: [f32; 3]) {
+            for axis in 0..3 {
+                let prev = self.prev_velocity[axis];
+                let now = velocity[axis];
+                if prev.abs() > BOUNCE_THRESHOLD
+                    && now.abs() > BOUNCE_THRESHOLD
+                    && prev.signum() != now.signum()
+                {
+                    self.count += 1;
+                }
+            }
+            self.prev_velocity = velocity;
+        }
+    }
+}
+
+/// Runs the terminal frontend through `bhumi::app::App`'s plugin shell instead of the bespoke
+/// loop `main()` normally drives - installs `core_gameplay_plugin` for the step-and-render system
+/// every frontend needs, plus a small plugin of our own that feeds terminal input into the
+/// `World` each tick and presents the rendered `PixelBuffer` back out to the terminal. Selected
+/// via `--ecs`.
+mod ecs_backend {
+    use bevy_ecs::prelude::*;
+
+    use bhumi::app::{core_gameplay_plugin, step_renderer_system, App, InputEvents, RendererResource};
+    use bhumi::PixelRenderer;
+
+    use crate::TerminalRenderer;
+
+    /// Wraps the terminal's own `PixelRenderer` so it can live in the `World` as a resource,
+    /// rather than `App`'s plugins having to thread a handle to it through every system call.
+    #[derive(Resource)]
+    struct TerminalRendererResource(TerminalRenderer);
+
+    /// Drains whatever input the terminal backend has read since the last tick into the shared
+    /// `InputEvents` resource `step_renderer_system` consumes - must run before it.
+    fn terminal_input_system(
+        mut terminal: ResMut<TerminalRendererResource>,
+        mut input: ResMut<InputEvents>,
+    ) {
+        input.0.extend(terminal.0.handle_input());
+    }
+
+    /// Presents the frame `step_renderer_system` just rendered into `RendererResource` - must run
+    /// after it.
+    fn terminal_present_system(
+        renderer: Res<RendererResource>,
+        mut terminal: ResMut<TerminalRendererResource>,
+    ) {
+        let _ = terminal.0.render_frame(&renderer.0.buffer);
+    }
+
+    /// Installs the terminal's input/present systems either side of `step_renderer_system`, so
+    /// the terminal frontend is just two plugins rather than a bespoke main loop.
+    fn terminal_plugin(app: &mut App) {
+        app.world
+            .insert_resource(TerminalRendererResource(TerminalRenderer::new()));
+        app.add_system(terminal_input_system.before(step_renderer_system));
+        app.add_system(terminal_present_system.after(step_renderer_system));
+    }
+
+    /// Runs the terminal frontend entirely through `bhumi::app::App`, selected from `main()` via
+    /// the `--ecs` flag - an alternative to the bespoke loop below, for when gameplay systems are
+    /// shared with other ECS-based frontends instead of each owning a private main loop.
+    pub fn run() -> std::io::Result<()> {
+        crossterm::terminal::enable_raw_mode()?;
+
+        let mut app = App::new();
+        app.add_plugin(core_gameplay_plugin);
+        app.add_plugin(terminal_plugin);
+
+        loop {
+            app.update();
+            let should_exit = app
+                .world
+                .get_resource::<TerminalRendererResource>()
+                .map(|terminal| terminal.0.should_exit())
+                .unwrap_or(true);
+            if should_exit {
+                break;
+            }
+        }
+
+        crossterm::terminal::disable_raw_mode()?;
+        Ok(())
+    }
+}
+
+/// Palette quantization + Floyd–Steinberg dithering for the LowRes render mode, so its
+/// "retro, low color" look comes from an actual reduced palette instead of just relying on
+/// viuer's `truecolor: false` flag.
+mod palette {
+    use bhumi::PixelBuffer;
+
+    /// A 16-color retro-ish swatch (roughly CGA/EGA primaries plus a grayscale ramp).
+    pub const PALETTE: [[u8; 3]; 16] = [
+        [0, 0, 0],
+        [128, 0, 0],
+        [0, 128, 0],
+        [128, 128, 0],
+        [0, 0, 128],
+        [128, 0, 128],
+        [0, 128, 128],
+        [192, 192, 192],
+        [96, 96, 96],
+        [255, 0, 0],
+        [0, 255, 0],
+        [255, 255, 0],
+        [0, 0, 255],
+        [255, 0, 255],
+        [0, 255, 255],
+        [255, 255, 255],
+    ];
+
+    fn nearest(color: [u8; 3]) -> [u8; 3] {
+        PALETTE
+            .iter()
+            .copied()
+            .min_by_key(|p| {
+                let dr = p[0] as i32 - color[0] as i32;
+                let dg = p[1] as i32 - color[1] as i32;
+                let db = p[2] as i32 - color[2] as i32;
+                dr * dr + dg * dg + db * db
+            })
+            .unwrap()
+    }
+
+    /// Floyd–Steinberg dither `buffer` down to `PALETTE`, returning RGBA bytes at the same
+    /// dimensions as the input (alpha untouched).
+    pub fn quantize_dither(buffer: &PixelBuffer) -> Vec<u8> {
+        let width = buffer.width as usize;
+        let height = buffer.height as usize;
+
+        // Working buffer in floating point so the diffused error can push a channel below 0 or
+        // above 255 without wrapping.
+        let mut working: Vec<[f32; 3]> = buffer
+            .pixels
+            .iter()
+            .map(|p| [p[0] as f32, p[1] as f32, p[2] as f32])
+            .collect();
+        let mut out = vec![0u8; width * height * 4];
+
+        for y in 0..height {
+            for x in 0..width {
+                let idx = y * width + x;
+                let current = [
+                    working[idx][0].clamp(0.0, 255.0),
+                    working[idx][1].clamp(0.0, 255.0),
+                    working[idx][2].clamp(0.0, 255.0),
+                ];
+                let quantized = nearest([current[0] as u8, current[1] as u8, current[2] as u8]);
+                let error = [
+                    current[0] - quantized[0] as f32,
+                    current[1] - quantized[1] as f32,
+                    current[2] - quantized[2] as f32,
+                ];
+
+                let mut diffuse = |dx: i32, dy: i32, weight: f32| {
+                    let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+                    if nx >= 0 && nx < width as i32 && ny >= 0 && ny < height as i32 {
+                        let nidx = ny as usize * width + nx as usize;
+                        for c in 0..3 {
+                            working[nidx][c] += error[c] * weight;
+                        }
+                    }
+                };
+                diffuse(1, 0, 7.0 / 16.0);
+                diffuse(-1, 1, 3.0 / 16.0);
+                diffuse(0, 1, 5.0 / 16.0);
+                diffuse(1, 1, 1.0 / 16.0);
+
+                out[idx * 4] = quantized[0];
+                out[idx * 4 + 1] = quantized[1];
+                out[idx * 4 + 2] = quantized[2];
+                out[idx * 4 + 3] = buffer.pixels[idx][3];
+            }
+        }
+
+        out
+    }
+}
+
+/// Block-character terminal renderer: each cell is a truecolor-background space, sampled from
+/// the pixel buffer. Tracks the previous frame's cell colors so only the cells that actually
+/// changed are redrawn, instead of repainting the whole grid every frame.
+mod block {
+    use std::io::{self, Write};
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    pub struct Cell {
+        pub bg: [u8; 3],
+    }
+
+    pub struct CellGrid {
+        width: u16,
+        height: u16,
+        cells: Vec<Option<Cell>>,
+    }
+
+    impl CellGrid {
+        pub fn new(width: u16, height: u16) -> Self {
+            Self {
+                width,
+                height,
+                cells: vec![None; width as usize * height as usize],
+            }
+        }
+
+        /// Force every cell to redraw on the next `draw` call, e.g. after a mode switch or a
+        /// full-screen clear that invalidated whatever the terminal is actually showing.
+        pub fn invalidate(&mut self) {
+            self.cells.fill(None);
+        }
+
+        /// Draw `new_cells` (row-major, `width`×`height` cells) at `origin_x`/`origin_y`,
+        /// writing an ANSI background-color + cursor-move only for cells that changed since the
+        /// last call.
+        pub fn draw(
+            &mut self,
+            out: &mut impl Write,
+            origin_x: u16,
+            origin_y: u16,
+            new_cells: &[Cell],
+        ) -> io::Result<()> {
+            for y in 0..self.height {
+                for x in 0..self.width {
+                    let idx = y as usize * self.width as usize + x as usize;
+                    let new_cell = new_cells[idx];
+                    if self.cells[idx] != Some(new_cell) {
+                        write!(
+                            out,
+                            "\x1b[{};{}H\x1b[48;2;{};{};{}m ",
+                            origin_y + y + 1,
+                            origin_x + x + 1,
+                            new_cell.bg[0],
+                            new_cell.bg[1],
+                            new_cell.bg[2],
+                        )?;
+                        self.cells[idx] = Some(new_cell);
+                    }
+                }
+            }
+            write!(out, "\x1b[0m")?;
+            out.flush()
+        }
+    }
+}
+
+/// Deterministic frame/input recording and replay, for reproducing a flight without a live
+/// terminal in front of it (bug reports, regression checks). No serde anywhere in this
+/// codebase, so the log is a plain text format: one line per frame, `dt|event,event,...`.
+mod replay {
+    use bhumi::InputEvent;
+    use std::fs::File;
+    use std::io::{self, BufRead, BufReader, Write};
+
+    fn event_to_str(event: &InputEvent) -> &'static str {
+        match event {
+            InputEvent::ThrustForward => "thrust_forward",
+            InputEvent::ThrustBackward => "thrust_backward",
+            InputEvent::ThrustLeft => "thrust_left",
+            InputEvent::ThrustRight => "thrust_right",
+            InputEvent::ThrustUp => "thrust_up",
+            InputEvent::ThrustDown => "thrust_down",
+            InputEvent::Reset => "reset",
+            InputEvent::Stop => "stop",
+            InputEvent::Exit => "exit",
+        }
+    }
+
+    fn event_from_str(name: &str) -> Option<InputEvent> {
+        match name {
+            "thrust_forward" => Some(InputEvent::ThrustForward),
+            "thrust_backward" => Some(InputEvent::ThrustBackward),
+            "thrust_left" => Some(InputEvent::ThrustLeft),
+            "thrust_right" => Some(InputEvent::ThrustRight),
+            "thrust_up" => Some(InputEvent::ThrustUp),
+            "thrust_down" => Some(InputEvent::ThrustDown),
+            "reset" => Some(InputEvent::Reset),
+            "stop" => Some(InputEvent::Stop),
+            "exit" => Some(InputEvent::Exit),
+            _ => None,
+        }
+    }
+
+    /// First line of every recording: crate version plus the drone's initial position/velocity,
+    /// so a replay can be checked against the build and starting state it was recorded under.
+    #[derive(Debug, Clone, Copy)]
+    pub struct RecordingHeader {
+        pub drone_pos: [f32; 3],
+        pub drone_vel: [f32; 3],
+    }
+
+    impl RecordingHeader {
+        fn write(&self, out: &mut impl Write) -> io::Result<()> {
+            writeln!(
+                out,
+                "bhumi-replay v{}|pos={},{},{}|vel={},{},{}",
+                env!("CARGO_PKG_VERSION"),
+                self.drone_pos[0],
+                self.drone_pos[1],
+                self.drone_pos[2],
+                self.drone_vel[0],
+                self.drone_vel[1],
+                self.drone_vel[2],
+            )
+        }
+
+        fn parse(line: &str) -> Option<Self> {
+            let mut parts = line.split('|');
+            parts.next()?; // "bhumi-replay vX.Y.Z" - informational only
+            let pos = parse_vec3(parts.next()?.strip_prefix("pos=")?)?;
+            let vel = parse_vec3(parts.next()?.strip_prefix("vel=")?)?;
+            Some(Self {
+                drone_pos: pos,
+                drone_vel: vel,
+            })
+        }
+    }
+
+    fn parse_vec3(s: &str) -> Option<[f32; 3]> {
+        let mut parts = s.split(',');
+        let x = parts.next()?.parse().ok()?;
+        let y = parts.next()?.parse().ok()?;
+        let z = parts.next()?.parse().ok()?;
+        Some([x, y, z])
+    }
+
+    /// Appends one `dt|events` line per frame to the log file, after a `RecordingHeader`.
+    pub struct Recorder {
+        file: File,
+    }
+
+    impl Recorder {
+        pub fn create(path: &str, header: RecordingHeader) -> io::Result<Self> {
+            let mut file = File::create(path)?;
+            header.write(&mut file)?;
+            Ok(Self { file })
+        }
+
+        pub fn record_frame(&mut self, dt: f32, events: &[InputEvent]) -> io::Result<()> {
+            let events_str = events
+                .iter()
+                .map(event_to_str)
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(self.file, "{}|{}", dt, events_str)
+        }
+    }
+
+    /// Plays back a log file written by `Recorder`, one frame at a time.
+    pub struct Player {
+        pub header: RecordingHeader,
+        frames: std::vec::IntoIter<(f32, Vec<InputEvent>)>,
+    }
+
+    impl Player {
+        pub fn load(path: &str) -> io::Result<Self> {
+            let mut lines = BufReader::new(File::open(path)?).lines();
+            let header_line = lines.next().transpose()?.unwrap_or_default();
+            let header = RecordingHeader::parse(&header_line).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing or malformed replay header")
+            })?;
+
+            let mut frames = Vec::new();
+            for line in lines {
+                let line = line?;
+                let Some((dt_str, events_str)) = line.split_once('|') else {
+                    continue;
+                };
+                let Ok(dt) = dt_str.parse::<f32>() else {
+                    continue;
+                };
+                let events = events_str
+                    .split(',')
+                    .filter_map(event_from_str)
+                    .collect::<Vec<_>>();
+                frames.push((dt, events));
+            }
+            Ok(Self {
+                header,
+                frames: frames.into_iter(),
+            })
+        }
+
+        /// Returns the next recorded `(dt, events)` pair, or `None` once the log is exhausted.
+        pub fn next_frame(&mut self) -> Option<(f32, Vec<InputEvent>)> {
+            self.frames.next()
+        }
+    }
 }
 
 impl PixelRenderer for TerminalRenderer {
@@ -44,6 +843,10 @@ impl PixelRenderer for TerminalRenderer {
             log_file,
             frame_count: 0,
             render_mode: ViuerMode::Auto,
+            cleared_once: false,
+            block_grid: block::CellGrid::new(80, 30),
+            keymap: keymap::Keymap::load_or_default(KEYMAP_CONFIG_PATH),
+            pending_rewind: None,
         }
     }
 
@@ -53,13 +856,20 @@ impl PixelRenderer for TerminalRenderer {
             self.show_physics = true;
         }
 
-        // Clear screen
-        print!("\x1b[2J\x1b[H"); // Clear screen and move cursor to top
-
         if self.show_physics {
-            // Use viuer to display our pixel buffer
-            self.draw_pixel_buffer_with_viuer(buffer)?;
+            // Only wipe the terminal once per run/mode-switch; the Block path then redraws just
+            // the cells that changed, and the Kitty path redraws its whole image in place.
+            if !self.cleared_once {
+                print!("\x1b[2J\x1b[H");
+                self.cleared_once = true;
+            }
+            match self.render_mode {
+                ViuerMode::Kitty => self.draw_pixel_buffer_with_kitty(buffer)?,
+                ViuerMode::Block => self.draw_pixel_buffer_with_blocks(buffer)?,
+                _ => self.draw_pixel_buffer_with_viuer(buffer)?,
+            }
         } else {
+            print!("\x1b[2J\x1b[H");
             // Show simple splash
             println!("bhumi v{}", env!("CARGO_PKG_VERSION"));
         }
@@ -74,29 +884,37 @@ impl PixelRenderer for TerminalRenderer {
             if let Ok(event) = event::read() {
                 match event {
                     Event::Key(k) => {
-                        match k.code {
-                            KeyCode::Char('q') | KeyCode::Esc => {
-                                self.should_exit = true;
-                                events.push(InputEvent::Exit);
-                            },
-                            KeyCode::Char('w') | KeyCode::Up => events.push(InputEvent::ThrustForward),
-                            KeyCode::Char('s') | KeyCode::Down => events.push(InputEvent::ThrustBackward), 
-                            KeyCode::Char('a') | KeyCode::Left => events.push(InputEvent::ThrustLeft),
-                            KeyCode::Char('d') | KeyCode::Right => events.push(InputEvent::ThrustRight),
-                            KeyCode::Char(' ') => events.push(InputEvent::ThrustUp),
-                            KeyCode::Char('c') => events.push(InputEvent::ThrustDown),
-                            KeyCode::Tab => {
-                                // Toggle viuer rendering mode
-                                self.render_mode = match self.render_mode {
-                                    ViuerMode::Auto => ViuerMode::Block,
-                                    ViuerMode::Block => ViuerMode::LowRes,
-                                    ViuerMode::LowRes => ViuerMode::Auto,
-                                };
-                                self.log(&format!("Switched to render mode: {:?}", self.render_mode));
-                            },
-                            KeyCode::Char('0') => events.push(InputEvent::Reset),
-                            KeyCode::Char('9') => events.push(InputEvent::Stop),
-                            _ => {}
+                        if let Some(action) = self.keymap.action_for(k.code) {
+                            match action {
+                                keymap::Action::Exit => {
+                                    self.should_exit = true;
+                                    events.push(InputEvent::Exit);
+                                }
+                                keymap::Action::ThrustForward => events.push(InputEvent::ThrustForward),
+                                keymap::Action::ThrustBackward => events.push(InputEvent::ThrustBackward),
+                                keymap::Action::ThrustLeft => events.push(InputEvent::ThrustLeft),
+                                keymap::Action::ThrustRight => events.push(InputEvent::ThrustRight),
+                                keymap::Action::ThrustUp => events.push(InputEvent::ThrustUp),
+                                keymap::Action::ThrustDown => events.push(InputEvent::ThrustDown),
+                                keymap::Action::CycleRenderMode => {
+                                    self.render_mode = match self.render_mode {
+                                        ViuerMode::Auto => ViuerMode::Block,
+                                        ViuerMode::Block => ViuerMode::LowRes,
+                                        ViuerMode::LowRes => ViuerMode::Kitty,
+                                        ViuerMode::Kitty => ViuerMode::Auto,
+                                    };
+                                    self.log(&format!("Switched to render mode: {:?}", self.render_mode));
+                                    // The previous mode may have left pixels on screen the new
+                                    // one won't overwrite; force a full clear and a fresh diff
+                                    // baseline.
+                                    self.cleared_once = false;
+                                    self.block_grid.invalidate();
+                                }
+                                keymap::Action::Reset => events.push(InputEvent::Reset),
+                                keymap::Action::Stop => events.push(InputEvent::Stop),
+                                keymap::Action::RewindBack => self.pending_rewind = Some(-1),
+                                keymap::Action::RewindForward => self.pending_rewind = Some(1),
+                            }
                         }
                     },
                     _ => {}
@@ -113,6 +931,11 @@ impl PixelRenderer for TerminalRenderer {
 }
 
 impl TerminalRenderer {
+    /// Takes the pending rewind direction set by the last `handle_input` call, if any.
+    fn take_rewind(&mut self) -> Option<i32> {
+        self.pending_rewind.take()
+    }
+
     /// Log debug message to file
     fn log(&mut self, message: &str) {
         use std::io::Write;
@@ -169,10 +992,14 @@ impl TerminalRenderer {
             },
         };
 
-        // Convert our pixel buffer to format that image crate expects
-        let rgba_bytes: Vec<u8> = buffer.pixels.iter()
-            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
-            .collect();
+        // LowRes gets its retro look from an actual reduced, dithered palette; everything else
+        // passes the buffer through untouched.
+        let rgba_bytes: Vec<u8> = match self.render_mode {
+            ViuerMode::LowRes => palette::quantize_dither(buffer),
+            _ => buffer.pixels.iter()
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+                .collect(),
+        };
 
         // Create RgbaImage from raw bytes
         if let Some(rgba_image) = RgbaImage::from_raw(buffer.width, buffer.height, rgba_bytes) {
@@ -191,6 +1018,51 @@ impl TerminalRenderer {
 
         Ok(())
     }
+
+    /// Display the pixel buffer with our own Kitty graphics protocol encoder, skipping viuer
+    /// entirely. Cursor is positioned first so the image lands at the same centered spot the
+    /// viuer path uses.
+    fn draw_pixel_buffer_with_kitty(&self, buffer: &PixelBuffer) -> Result<()> {
+        let (term_w, term_h) = terminal::size().unwrap_or((80, 30));
+        let image_w = 80u16;
+        let image_h = 30u16;
+        let center_x = if term_w > image_w { (term_w - image_w) / 2 } else { 0 };
+        let center_y = if term_h > image_h { (term_h - image_h) / 2 } else { 0 };
+
+        execute!(std::io::stdout(), cursor::MoveTo(center_x, center_y))?;
+
+        let rgba_bytes: Vec<u8> = buffer.pixels.iter()
+            .flat_map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+            .collect();
+
+        kitty::write_image(&mut std::io::stdout(), buffer.width, buffer.height, &rgba_bytes)?;
+
+        Ok(())
+    }
+
+    /// Display the pixel buffer as a grid of truecolor-background cells, redrawing only the
+    /// cells that changed color since the previous frame (see `block::CellGrid`).
+    fn draw_pixel_buffer_with_blocks(&mut self, buffer: &PixelBuffer) -> Result<()> {
+        let (term_w, term_h) = terminal::size().unwrap_or((80, 30));
+        let grid_w = 80u16;
+        let grid_h = 30u16;
+        let origin_x = if term_w > grid_w { (term_w - grid_w) / 2 } else { 0 };
+        let origin_y = if term_h > grid_h { (term_h - grid_h) / 2 } else { 0 };
+
+        // Nearest-neighbor downsample of the 320×240 pixel buffer into the cell grid.
+        let mut cells = Vec::with_capacity(grid_w as usize * grid_h as usize);
+        for gy in 0..grid_h {
+            let py = gy as u32 * buffer.height / grid_h as u32;
+            for gx in 0..grid_w {
+                let px = gx as u32 * buffer.width / grid_w as u32;
+                let pixel = buffer.get_pixel(px, py).unwrap_or([0, 0, 0, 255]);
+                cells.push(block::Cell { bg: [pixel[0], pixel[1], pixel[2]] });
+            }
+        }
+
+        self.block_grid
+            .draw(&mut std::io::stdout(), origin_x, origin_y, &cells)
+    }
 }
 
 /// Interactive visual test mode - shows viuer modes one at a time
@@ -378,6 +1250,14 @@ fn show_summary(results: &[(String, bool, String)], configs: &[(&str, viuer::Con
     std::io::stdout().flush().ok();
 }
 
+/// Looks up `--flag <value>` in the argument list, returning the value that follows it.
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    args.iter()
+        .position(|a| a == flag)
+        .and_then(|i| args.get(i + 1))
+        .cloned()
+}
+
 /// Raw mode for debugging
 fn print_raw_grid() -> Result<()> {
     let mut renderer = Renderer::new();
@@ -432,6 +1312,47 @@ fn main() -> Result<()> {
     if args.contains(&"--raw".to_string()) {
         return print_raw_grid();
     }
+    if args.contains(&"--drm".to_string()) {
+        #[cfg(feature = "drm")]
+        {
+            return drm_backend::run();
+        }
+        #[cfg(not(feature = "drm"))]
+        {
+            eprintln!("--drm requires building with the `drm` cargo feature enabled");
+            return Ok(());
+        }
+    }
+    // --window opts into a real OS window via minifb instead of the terminal/viuer renderer
+    // below, for when a window is available (or the terminal's graphics protocols aren't
+    // cooperating).
+    if args.contains(&"--window".to_string()) {
+        return main_minifb::run();
+    }
+    // --ecs runs the same gameplay loop through `bhumi::app::App`'s plugin shell instead of the
+    // bespoke loop below, for comparison with other ECS-based frontends.
+    if args.contains(&"--ecs".to_string()) {
+        return ecs_backend::run();
+    }
+
+    // --record <path> logs each frame's dt and resolved input events; --replay <path> feeds a
+    // previously recorded log back in instead of reading live input, for deterministic repro.
+    let record_path = flag_value(&args, "--record");
+    let replay_path = flag_value(&args, "--replay");
+
+    // --script <path.rhai> drops in a scripted autopilot that drives thrust in place of (or
+    // alongside) live keyboard input, compiled once here rather than re-parsed every frame.
+    let script_path = flag_value(&args, "--script");
+    let mut script = match &script_path {
+        Some(path) => match autopilot::ScriptController::compile(path) {
+            Ok(controller) => Some(controller),
+            Err(err) => {
+                eprintln!("failed to compile {}: {}", path, err);
+                None
+            }
+        },
+        None => None,
+    };
 
     // Setup terminal for raw mode
     terminal::enable_raw_mode()?;
@@ -439,26 +1360,102 @@ fn main() -> Result<()> {
     // Create renderer instances
     let mut terminal_renderer = TerminalRenderer::new();
     terminal_renderer.log("App started - creating core renderer");
-    
+
     let mut core_renderer = Renderer::new();
     let drone_pos = core_renderer.get_drone_position();
-    terminal_renderer.log(&format!("Core renderer created - initial drone pos: x={:.3}, y={:.3}, z={:.3}", 
+    terminal_renderer.log(&format!("Core renderer created - initial drone pos: x={:.3}, y={:.3}, z={:.3}",
         drone_pos[0], drone_pos[1], drone_pos[2]));
-    
+
+    let mut recorder = match &record_path {
+        Some(path) => {
+            let header = replay::RecordingHeader {
+                drone_pos,
+                drone_vel: core_renderer.get_drone_velocity(),
+            };
+            Some(replay::Recorder::create(path, header)?)
+        }
+        None => None,
+    };
+    let mut player = match &replay_path {
+        Some(path) => {
+            let player = replay::Player::load(path)?;
+            terminal_renderer.log(&format!(
+                "Replaying {} - recorded initial drone pos: x={:.3}, y={:.3}, z={:.3}",
+                path, player.header.drone_pos[0], player.header.drone_pos[1], player.header.drone_pos[2]
+            ));
+            Some(player)
+        }
+        None => None,
+    };
+
     let mut last_instant = std::time::Instant::now();
+    // In-memory scrub history, so `[`/`]` can rewind/fast-forward live flight - not used while
+    // replaying a log, which already has its own fixed, linear timeline.
+    let mut rewind_recorder = rewind::Recorder::new();
+    let mut bounce_counter = bounce::Counter::new();
+    // The script error last written to the log, so a repeating error is logged only once.
+    let mut logged_script_error: Option<String> = None;
 
     // Main loop
     loop {
         let now = std::time::Instant::now();
-        let dt = (now - last_instant).as_secs_f32();
+        let mut dt = (now - last_instant).as_secs_f32();
         last_instant = now;
 
-        // Handle input
-        let input_events = terminal_renderer.handle_input();
+        // Handle input: replaying takes over both the events and the timestep, so the run is
+        // reproduced exactly rather than just approximately.
+        let mut input_events = if let Some(player) = player.as_mut() {
+            match player.next_frame() {
+                Some((recorded_dt, events)) => {
+                    dt = recorded_dt;
+                    events
+                }
+                None => {
+                    terminal_renderer.log("Replay finished - exiting");
+                    break;
+                }
+            }
+        } else {
+            terminal_renderer.handle_input()
+        };
         if terminal_renderer.should_exit() {
             break;
         }
 
+        if player.is_none() {
+            if let Some(direction) = terminal_renderer.take_rewind() {
+                let target = if direction < 0 {
+                    rewind_recorder.frame().saturating_sub(rewind::STEP_FRAMES)
+                } else {
+                    (rewind_recorder.frame() + rewind::STEP_FRAMES).min(rewind_recorder.live_frame())
+                };
+                rewind_recorder.rewind(&mut core_renderer, target);
+            }
+
+            if let Some(controller) = script.as_mut() {
+                let sim_time = rewind_recorder.frame() as f32 * bhumi::physics::FIXED_DT;
+                if let Some(event) = controller.thrust_event(
+                    core_renderer.get_drone_position(),
+                    core_renderer.get_drone_velocity(),
+                    sim_time,
+                ) {
+                    input_events.push(event);
+                }
+                match controller.last_error() {
+                    Some(err) if logged_script_error.as_deref() != Some(err) => {
+                        terminal_renderer.log(&format!("Script error: {}", err));
+                        logged_script_error = Some(err.to_string());
+                    }
+                    Some(_) => {}
+                    None => logged_script_error = None,
+                }
+            }
+        }
+
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.record_frame(dt, &input_events)?;
+        }
+
         // Log input events
         if !input_events.is_empty() {
             terminal_renderer.log(&format!("Input events: {:?}", input_events));
@@ -468,13 +1465,18 @@ fn main() -> Result<()> {
         core_renderer.update(dt, &input_events);
         core_renderer.render();
 
+        bounce_counter.update(core_renderer.get_drone_velocity());
+        if player.is_none() {
+            rewind_recorder.record(&core_renderer, dt, &input_events);
+        }
+
         // Log drone position occasionally
         terminal_renderer.frame_count += 1;
         if terminal_renderer.frame_count % 60 == 0 { // Every ~2 seconds
             let pos = core_renderer.get_drone_position();
             let vel = core_renderer.get_drone_velocity();
-            terminal_renderer.log(&format!("Frame {}: Drone pos: x={:.3}, y={:.3}, z={:.3}, vel: x={:.3}, y={:.3}, z={:.3}", 
-                terminal_renderer.frame_count, pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]));
+            terminal_renderer.log(&format!("Frame {}: Drone pos: x={:.3}, y={:.3}, z={:.3}, vel: x={:.3}, y={:.3}, z={:.3}, bounces: {}",
+                terminal_renderer.frame_count, pos[0], pos[1], pos[2], vel[0], vel[1], vel[2], bounce_counter.count));
         }
 
         // Render to terminal with viuer
@@ -0,0 +1,132 @@
+// Native windowed backend using minifb - an alternative to the terminal/viuer PixelRenderer,
+// useful when running somewhere a real window is available (or the terminal's graphics
+// protocols just aren't cooperating).
+use std::io::Result;
+use std::time::Duration;
+
+use bhumi::{InputEvent, PixelBuffer, PixelRenderer, Renderer};
+use minifb::{Key, Window, WindowOptions};
+
+const WINDOW_SCALE: usize = 3;
+
+/// Renders the shared `PixelBuffer` into a real OS window via minifb, instead of through a
+/// terminal graphics protocol.
+struct MinifbRenderer {
+    window: Window,
+    should_exit: bool,
+}
+
+impl PixelRenderer for MinifbRenderer {
+    fn new() -> Self {
+        let buffer = PixelBuffer::new();
+        let width = buffer.width as usize * WINDOW_SCALE;
+        let height = buffer.height as usize * WINDOW_SCALE;
+
+        let mut window = Window::new(
+            "bhumi (minifb)",
+            width,
+            height,
+            WindowOptions::default(),
+        )
+        .expect("Failed to open minifb window");
+
+        // Cap to ~60 FPS; minifb has no vsync of its own.
+        window.limit_update_rate(Some(Duration::from_micros(16_600)));
+
+        Self {
+            window,
+            should_exit: false,
+        }
+    }
+
+    fn render_frame(&mut self, buffer: &PixelBuffer) -> Result<()> {
+        // minifb wants one packed u32 (0x00RRGGBB) per pixel, scaled up by WINDOW_SCALE since a
+        // 320×240 window would be tiny on most monitors.
+        let scaled_width = buffer.width as usize * WINDOW_SCALE;
+        let scaled_height = buffer.height as usize * WINDOW_SCALE;
+        let mut argb = vec![0u32; scaled_width * scaled_height];
+
+        for y in 0..scaled_height {
+            let src_y = y / WINDOW_SCALE;
+            for x in 0..scaled_width {
+                let src_x = x / WINDOW_SCALE;
+                if let Some(pixel) = buffer.get_pixel(src_x as u32, src_y as u32) {
+                    let packed = ((pixel[0] as u32) << 16) | ((pixel[1] as u32) << 8) | pixel[2] as u32;
+                    argb[y * scaled_width + x] = packed;
+                }
+            }
+        }
+
+        self.window
+            .update_with_buffer(&argb, scaled_width, scaled_height)
+            .expect("Failed to update minifb window buffer");
+
+        if !self.window.is_open() || self.window.is_key_down(Key::Escape) {
+            self.should_exit = true;
+        }
+
+        Ok(())
+    }
+
+    fn handle_input(&mut self) -> Vec<InputEvent> {
+        let mut events = Vec::new();
+
+        if self.window.is_key_down(Key::W) || self.window.is_key_down(Key::Up) {
+            events.push(InputEvent::ThrustForward);
+        }
+        if self.window.is_key_down(Key::S) || self.window.is_key_down(Key::Down) {
+            events.push(InputEvent::ThrustBackward);
+        }
+        if self.window.is_key_down(Key::A) || self.window.is_key_down(Key::Left) {
+            events.push(InputEvent::ThrustLeft);
+        }
+        if self.window.is_key_down(Key::D) || self.window.is_key_down(Key::Right) {
+            events.push(InputEvent::ThrustRight);
+        }
+        if self.window.is_key_down(Key::Space) {
+            events.push(InputEvent::ThrustUp);
+        }
+        if self.window.is_key_down(Key::C) {
+            events.push(InputEvent::ThrustDown);
+        }
+        if self.window.is_key_pressed(Key::Key0, minifb::KeyRepeat::No) {
+            events.push(InputEvent::Reset);
+        }
+        if self.window.is_key_pressed(Key::Key9, minifb::KeyRepeat::No) {
+            events.push(InputEvent::Stop);
+        }
+        if self.window.is_key_down(Key::Escape) {
+            self.should_exit = true;
+            events.push(InputEvent::Exit);
+        }
+
+        events
+    }
+
+    fn should_exit(&self) -> bool {
+        self.should_exit
+    }
+}
+
+/// Runs the minifb-backed main loop, selected from `main()` via the `--window` flag.
+pub fn run() -> Result<()> {
+    let mut minifb_renderer = MinifbRenderer::new();
+    let mut core_renderer = Renderer::new();
+
+    let mut last_instant = std::time::Instant::now();
+
+    while !minifb_renderer.should_exit() {
+        let now = std::time::Instant::now();
+        let dt = (now - last_instant).as_secs_f32();
+        last_instant = now;
+
+        let input_events = minifb_renderer.handle_input();
+
+        core_renderer.update(dt, &input_events);
+        core_renderer.render();
+
+        minifb_renderer.render_frame(&core_renderer.buffer)?;
+    }
+
+    Ok(())
+}
@@ -5,6 +5,7 @@ use std::collections::HashSet;
 
 // Use the exact same bhumi core as other backends
 use bhumi::{Renderer, InputEvent};
+use bhumi::netcode::{InputBits, RollbackBuffer};
 
 // Import console.log
 #[wasm_bindgen]
@@ -29,6 +30,15 @@ pub struct BhumiWeb {
     // Input state
     keys_pressed: HashSet<String>,
     last_frame: f64,
+
+    /// Held WASD/Space/C/IJKL keys, packed the same way a rollback netcode peer would transmit them -
+    /// see `update`'s fixed-timestep loop.
+    input_bits: InputBits,
+    /// Drives `core_renderer.physics` deterministically so a future network peer's remote inputs
+    /// can be reconciled against it via rollback.
+    rollback: RollbackBuffer,
+    /// Leftover real time (in seconds) not yet consumed by a `physics::FIXED_DT` step.
+    frame_accumulator: f32,
 }
 
 #[wasm_bindgen]
@@ -46,6 +56,9 @@ impl BhumiWeb {
             ctx: None,
             keys_pressed: HashSet::new(),
             last_frame: 0.0,
+            input_bits: InputBits::empty(),
+            rollback: RollbackBuffer::new(),
+            frame_accumulator: 0.0,
         }
     }
     
@@ -74,31 +87,128 @@ impl BhumiWeb {
     #[wasm_bindgen]
     pub fn handle_key_down(&mut self, key: String) {
         self.keys_pressed.insert(key.clone());
-        
-        // Convert to InputEvent and send to bhumi core (same as GUI)
-        let input_event = match key.as_str() {
-            "KeyW" | "w" => Some(InputEvent::ThrustForward),
-            "KeyS" | "s" => Some(InputEvent::ThrustBackward),
-            "KeyA" | "a" => Some(InputEvent::ThrustLeft),
-            "KeyD" | "d" => Some(InputEvent::ThrustRight),
-            "Space" | " " => Some(InputEvent::ThrustUp),
-            "KeyC" | "c" => Some(InputEvent::ThrustDown),
-            "KeyI" | "i" => Some(InputEvent::SteerPitchUp),
-            "KeyK" | "k" => Some(InputEvent::SteerPitchDown),
-            "KeyJ" | "j" => Some(InputEvent::SteerYawLeft),
-            "KeyL" | "l" => Some(InputEvent::SteerYawRight),
-            _ => None,
-        };
-        
-        if let Some(event) = input_event {
-            console_log!("🎮 Input: {:?}", event);
-            self.core_renderer.update(0.016, &[event]);
+
+        // The WASD/Space/C/IJKL cluster feeds the rollback-deterministic `input_bits` that
+        // `update`'s fixed-timestep loop steps `core_renderer.physics` with - held-key state
+        // rather than a one-shot `InputEvent`, since a rollback peer's bits need to reflect
+        // "still held" across every `FIXED_DT` tick, not just the frame the key was pressed on.
+        if let Some(bit) = key_to_input_bit(&key) {
+            self.input_bits.set(bit);
+        }
+
+        // `C` is already bound to thrust-down above, so camera cycling gets its own key here
+        // instead of colliding with it - mirrors the `bhumi-wgpu` backend's choice of `V`.
+        if key == "KeyV" || key == "v" {
+            self.core_renderer.cycle_camera();
         }
     }
-    
+
     #[wasm_bindgen]
     pub fn handle_key_up(&mut self, key: String) {
         self.keys_pressed.remove(&key);
+
+        if let Some(bit) = key_to_input_bit(&key) {
+            self.input_bits.clear(bit);
+        }
+    }
+
+    /// Feeds relative mouse motion (e.g. from a pointer-locked `mousemove` listener) into the
+    /// core renderer's `Flycam`/`FreeFly`/`FirstPerson` look direction immediately, through its
+    /// own `core_renderer.update` call rather than `update`'s fixed-timestep loop.
+    #[wasm_bindgen]
+    pub fn handle_mouse_move(&mut self, dx: f64, dy: f64) {
+        self.core_renderer
+            .update(0.016, &[InputEvent::MouseLook { dx: dx as f32, dy: dy as f32 }]);
+    }
+
+    /// Polls every connected `Gamepad` and feeds its sticks/triggers into the core renderer, the
+    /// same analog `Thrust`/`Torque` events the desktop `sdl2` backend feeds from its own
+    /// controller axes. Call once per frame (e.g. from `update`'s caller) since the Gamepad API
+    /// has no connect/poll event of its own, only a snapshot read.
+    #[wasm_bindgen]
+    pub fn poll_gamepad(&mut self) {
+        let Some(window) = web_sys::window() else { return };
+        let Ok(gamepads) = window.navigator().get_gamepads() else { return };
+
+        for i in 0..gamepads.length() {
+            let value = gamepads.get(i);
+            if value.is_null() {
+                continue;
+            }
+            if let Ok(gamepad) = value.dyn_into::<Gamepad>() {
+                self.process_gamepad(&gamepad);
+            }
+        }
+    }
+
+    /// Reads one gamepad's left stick (surge/sway), right stick (pitch/yaw), and triggers
+    /// (heave). The sticks are scaled through `scaled_axis`'s dead zone and the triggers use their
+    /// raw analog value, so partial deflection is respected instead of snapping straight to full
+    /// thrust.
+    fn process_gamepad(&mut self, gamepad: &Gamepad) {
+        let axes = gamepad.axes();
+        let axis = |index: u32| axes.get(index).as_f64().map(scaled_axis).unwrap_or(0.0);
+
+        if axes.length() >= 2 {
+            let left_x = axis(0);
+            let left_y = axis(1);
+            if left_x != 0.0 || left_y != 0.0 {
+                // Invert Y: the browser reports "stick forward" as a negative Y axis.
+                self.core_renderer
+                    .update(0.016, &[InputEvent::Thrust { x: left_x * 0.3, y: 0.0, z: -left_y * 0.3 }]);
+            }
+        }
+
+        if axes.length() >= 4 {
+            let right_x = axis(2);
+            let right_y = axis(3);
+            if right_x != 0.0 || right_y != 0.0 {
+                self.core_renderer
+                    .update(0.016, &[InputEvent::Torque { pitch: -right_y * 0.05, yaw: right_x * 0.05, roll: 0.0 }]);
+            }
+        }
+
+        // Standard gamepad mapping: buttons 6/7 are the analog left/right triggers.
+        let buttons = gamepad.buttons();
+        if buttons.length() > 7 {
+            if let Ok(trigger) = buttons.get(6).dyn_into::<web_sys::GamepadButton>() {
+                let v = trigger.value() as f32;
+                if v > 0.0 {
+                    self.core_renderer.update(0.016, &[InputEvent::Thrust { x: 0.0, y: -v * 0.5, z: 0.0 }]);
+                }
+            }
+            if let Ok(trigger) = buttons.get(7).dyn_into::<web_sys::GamepadButton>() {
+                let v = trigger.value() as f32;
+                if v > 0.0 {
+                    self.core_renderer.update(0.016, &[InputEvent::Thrust { x: 0.0, y: v * 0.5, z: 0.0 }]);
+                }
+            }
+        }
+    }
+
+    /// Converts a phone's tilt (`beta` = pitch, `gamma` = roll, both in the
+    /// `DeviceOrientationEvent`'s native degrees) into steering events, so the simulator is
+    /// flyable by tilting the handset instead of needing a keyboard. `alpha` (compass heading)
+    /// isn't used for control, but is accepted to match the browser event's signature wholesale.
+    #[wasm_bindgen]
+    pub fn handle_device_orientation(&mut self, _alpha: f64, beta: f64, gamma: f64) {
+        const DEADZONE_DEGREES: f64 = 5.0;
+        const MAX_TILT_DEGREES: f64 = 35.0;
+
+        let tilt_to_magnitude = |degrees: f64| -> f32 {
+            if degrees.abs() <= DEADZONE_DEGREES {
+                return 0.0;
+            }
+            (degrees.clamp(-MAX_TILT_DEGREES, MAX_TILT_DEGREES) / MAX_TILT_DEGREES) as f32
+        };
+
+        let pitch = tilt_to_magnitude(beta);
+        let roll = tilt_to_magnitude(gamma);
+
+        if pitch != 0.0 || roll != 0.0 {
+            self.core_renderer
+                .update(0.016, &[InputEvent::Torque { pitch: pitch * 0.05, yaw: 0.0, roll: roll * 0.05 }]);
+        }
     }
     
     #[wasm_bindgen]
@@ -109,10 +219,24 @@ impl BhumiWeb {
             ((timestamp - self.last_frame) / 1000.0).min(0.033) as f32
         };
         self.last_frame = timestamp;
-        
-        // Update bhumi core (same as other backends)
-        let input_events = Vec::new(); // Input handled in key events
-        self.core_renderer.update(dt, &input_events);
+
+        // Drive the physics at a fixed timestep via `rollback`, however choppy the real frame
+        // rate is - determinism (and therefore rollback reconciliation) depends on every step
+        // covering exactly one `FIXED_DT` slice of simulated time, never a variable one.
+        self.frame_accumulator += dt;
+        while self.frame_accumulator >= bhumi::physics::FIXED_DT {
+            self.rollback.advance(&mut self.core_renderer.physics, self.input_bits);
+            self.frame_accumulator -= bhumi::physics::FIXED_DT;
+        }
+
+        // Follow the drone with the camera once per rendered frame. `core_renderer.update` isn't
+        // called here - its own physics step would advance the sim a second time this frame,
+        // fighting the fixed-timestep loop above.
+        let drone_pos = self.core_renderer.physics.get_drone_position();
+        let drone_rot = self.core_renderer.physics.get_drone_rotation();
+        self.core_renderer
+            .camera
+            .update(drone_pos, drone_rot, dt);
         self.core_renderer.render(); // Generate 320x240 pixel buffer
     }
     
@@ -144,9 +268,12 @@ impl BhumiWeb {
             
             ctx.set_fill_style(&"white".into());
             ctx.set_font("12px monospace");
-            let info = format!("Pos: ({:.1},{:.1},{:.1}) Vel: ({:.1},{:.1},{:.1})", 
+            let info = format!("Pos: ({:.1},{:.1},{:.1}) Vel: ({:.1},{:.1},{:.1})",
                 pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]);
             ctx.fill_text(&info, 10.0, 20.0).ok();
+
+            let camera_info = format!("Camera: {}", self.core_renderer.active_camera_name());
+            ctx.fill_text(&camera_info, 10.0, 36.0).ok();
         }
     }
     
@@ -161,4 +288,54 @@ impl BhumiWeb {
         console_log!("🛑 Stop");
         self.core_renderer.update(0.016, &[InputEvent::GentleStop]);
     }
+
+    /// Lets the web UI toggle between arcade zero-g free flight, plain gravity, and gravity plus
+    /// aerodynamic drag/lift - unrecognized names fall back to `free_flight` rather than erroring.
+    #[wasm_bindgen]
+    pub fn set_flight_model(&mut self, model: &str) {
+        use bhumi::physics::FlightModel;
+
+        let model = match model {
+            "gravity" => FlightModel::Gravity { g: 9.8 },
+            "atmospheric" => FlightModel::Atmospheric {
+                g: 9.8,
+                drag: 0.05,
+                lift: 0.3,
+            },
+            _ => FlightModel::FreeFlight,
+        };
+        self.core_renderer.physics.set_flight_model(model);
+    }
+}
+
+/// Dead-zone applied to every gamepad axis before it's scaled into an `InputEvent`, so small
+/// stick drift near center doesn't register as input. Rescales the remaining travel to a
+/// magnitude of 0.0..=1.0 with the axis's sign kept, so a push just past the dead zone starts
+/// near zero rather than jumping straight to a large value.
+const GAMEPAD_DEADZONE: f64 = 0.15;
+
+fn scaled_axis(value: f64) -> f32 {
+    if value.abs() <= GAMEPAD_DEADZONE {
+        return 0.0;
+    }
+    let sign = value.signum();
+    (sign * (value.abs() - GAMEPAD_DEADZONE) / (1.0 - GAMEPAD_DEADZONE)) as f32
+}
+
+/// Maps a `KeyboardEvent.code`/`.key` to the `InputBits` bit it drives, mirroring
+/// `handle_key_down`'s previous one-shot `InputEvent` mapping one-for-one.
+fn key_to_input_bit(key: &str) -> Option<u16> {
+    match key {
+        "KeyW" | "w" => Some(InputBits::THRUST_FORWARD),
+        "KeyS" | "s" => Some(InputBits::THRUST_BACKWARD),
+        "KeyA" | "a" => Some(InputBits::THRUST_LEFT),
+        "KeyD" | "d" => Some(InputBits::THRUST_RIGHT),
+        "Space" | " " => Some(InputBits::THRUST_UP),
+        "KeyC" | "c" => Some(InputBits::THRUST_DOWN),
+        "KeyI" | "i" => Some(InputBits::STEER_PITCH_UP),
+        "KeyK" | "k" => Some(InputBits::STEER_PITCH_DOWN),
+        "KeyJ" | "j" => Some(InputBits::STEER_YAW_LEFT),
+        "KeyL" | "l" => Some(InputBits::STEER_YAW_RIGHT),
+        _ => None,
+    }
 }
\ No newline at end of file
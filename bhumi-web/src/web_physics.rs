@@ -1,16 +1,25 @@
 // Simple web-compatible physics (no rapier3d dependency)
 use glam::{Vec3, Quat};
 
+/// Fixed physics timestep, independent of the browser's requestAnimationFrame rate.
+const FIXED_DT: f32 = 1.0 / 120.0;
+/// Clamp on a single frame's elapsed time so a tab going to the background doesn't dump a huge
+/// backlog of steps into the accumulator loop once it's foregrounded again.
+const MAX_FRAME_TIME: f32 = 0.25;
+
 pub struct WebPhysicsWorld {
     // Drone state
     position: Vec3,
     velocity: Vec3,
     rotation: Quat,
     angular_velocity: Vec3,
-    
+
     // Physics constants
     linear_damping: f32,
     angular_damping: f32,
+
+    /// Leftover real time not yet consumed by a `FIXED_DT` step.
+    accumulator: f32,
 }
 
 impl WebPhysicsWorld {
@@ -22,31 +31,48 @@ impl WebPhysicsWorld {
             angular_velocity: Vec3::ZERO,
             linear_damping: 0.9,
             angular_damping: 0.9,
+            accumulator: 0.0,
         }
     }
-    
+
+    /// Advances the simulation by real-time `dt`, internally sliced into constant `FIXED_DT`
+    /// steps so damping and `from_scaled_axis` rotation integration stop depending on the
+    /// caller's frame rate. Returns the drone position interpolated across the leftover
+    /// fraction of a step, for smooth rendering between fixed updates.
     pub fn step(&mut self, dt: f32, force: Vec3) -> [f32; 3] {
+        let prev_position = self.position;
+
+        self.accumulator += dt.min(MAX_FRAME_TIME);
+        while self.accumulator >= FIXED_DT {
+            self.step_fixed(force);
+            self.accumulator -= FIXED_DT;
+        }
+
+        let alpha = (self.accumulator / FIXED_DT).clamp(0.0, 1.0);
+        let interpolated = prev_position.lerp(self.position, alpha);
+        [interpolated.x, interpolated.y, interpolated.z]
+    }
+
+    fn step_fixed(&mut self, force: Vec3) {
         // Apply force
         let acceleration = force;
-        self.velocity += acceleration * dt;
-        
+        self.velocity += acceleration * FIXED_DT;
+
         // Apply damping
         self.velocity *= self.linear_damping;
         self.angular_velocity *= self.angular_damping;
-        
+
         // Update position
-        self.position += self.velocity * dt;
-        
+        self.position += self.velocity * FIXED_DT;
+
         // Update rotation
         if self.angular_velocity.length() > 0.001 {
-            let rotation_delta = Quat::from_scaled_axis(self.angular_velocity * dt);
+            let rotation_delta = Quat::from_scaled_axis(self.angular_velocity * FIXED_DT);
             self.rotation = rotation_delta * self.rotation;
             self.rotation = self.rotation.normalize();
         }
-        
-        [self.position.x, self.position.y, self.position.z]
     }
-    
+
     pub fn apply_rotation_delta(&mut self, rotation_delta: Vec3) {
         let delta_quat = Quat::from_euler(
             glam::EulerRot::XYZ,
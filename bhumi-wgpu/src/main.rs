@@ -1,25 +1,122 @@
+use bhumi::actions::ActionHandler;
+use bhumi::input::{InputMap, Keys, Modifiers, Mouse, Key as MappedKey};
 use bhumi::{PixelRenderer, PixelBuffer, Renderer, InputEvent};
 use log::info;
 use pixels::{Pixels, SurfaceTexture, PixelsBuilder};
-use std::collections::HashSet;
 use std::time::Instant;
 use winit::{
     application::ApplicationHandler,
     dpi::LogicalSize,
-    event::{ElementState, KeyEvent, WindowEvent},
+    event::{DeviceEvent, DeviceId, ElementState, KeyEvent, MouseButton, WindowEvent},
     event_loop::{ActiveEventLoop, ControlFlow, EventLoop},
     keyboard::{KeyCode, PhysicalKey, ModifiersState},
-    window::{Window, WindowId, Fullscreen},
+    window::{CursorGrabMode, Window, WindowId, Fullscreen},
 };
 
+/// Translates a winit `KeyCode` into the backend-neutral `Key` the shared `InputMap` understands.
+/// Keys with no binding (arrow keys, function keys, etc.) return `None` and are ignored.
+fn mapped_key(code: KeyCode) -> Option<MappedKey> {
+    match code {
+        KeyCode::KeyW => Some(MappedKey::W),
+        KeyCode::KeyA => Some(MappedKey::A),
+        KeyCode::KeyS => Some(MappedKey::S),
+        KeyCode::KeyD => Some(MappedKey::D),
+        KeyCode::Space => Some(MappedKey::Space),
+        KeyCode::KeyC => Some(MappedKey::C),
+        KeyCode::KeyI => Some(MappedKey::I),
+        KeyCode::KeyJ => Some(MappedKey::J),
+        KeyCode::KeyK => Some(MappedKey::K),
+        KeyCode::KeyL => Some(MappedKey::L),
+        KeyCode::KeyU => Some(MappedKey::U),
+        KeyCode::KeyO => Some(MappedKey::O),
+        KeyCode::Digit0 => Some(MappedKey::Digit0),
+        KeyCode::Digit9 => Some(MappedKey::Digit9),
+        KeyCode::Escape => Some(MappedKey::Escape),
+        KeyCode::KeyQ => Some(MappedKey::Q),
+        _ => None,
+    }
+}
+
 const BUFFER_WIDTH: u32 = 320;
 const BUFFER_HEIGHT: u32 = 240;
 
-/// GPU renderer implementing the PixelRenderer trait (matching bhumi-terminal pattern)
+/// How the 320×240 `PixelBuffer` maps onto a window that isn't a whole multiple of its size,
+/// mirroring quicksilver's `ResizeStrategy`. It picks the initial window size and the rectangle
+/// `viewport_rect` computes, which is logged on window creation and resize; the frame itself is
+/// drawn by `pixels`' own scaling pass, which does not read that rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResizeStrategy {
+    /// Grow by whole multiples only, the window snapping to the largest one that fits.
+    IntegerScale,
+    /// Fit the buffer inside the window preserving its 4:3 ratio, letterboxing the other axis.
+    Fit,
+    /// Fill the window preserving the 4:3 ratio, cropping whichever axis overflows.
+    Fill,
+    /// Stretch to fill the window exactly, ignoring aspect ratio.
+    Stretch,
+}
+
+/// How the upscaled buffer is sampled between source pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleFilter {
+    /// Crisp, blocky pixels - the default for a retro-style renderer.
+    Nearest,
+    /// Smoothed/blurred upscaling.
+    Linear,
+}
+
+impl ScaleFilter {
+    fn wgpu_filter_mode(self) -> pixels::wgpu::FilterMode {
+        match self {
+            ScaleFilter::Nearest => pixels::wgpu::FilterMode::Nearest,
+            ScaleFilter::Linear => pixels::wgpu::FilterMode::Linear,
+        }
+    }
+}
+
+/// Computes the destination rectangle (x, y, width, height) that the 320×240 buffer should be
+/// drawn into within a `surface_width`×`surface_height` window, given a `ResizeStrategy`.
+fn viewport_rect(strategy: ResizeStrategy, surface_width: u32, surface_height: u32) -> (u32, u32, u32, u32) {
+    match strategy {
+        ResizeStrategy::Stretch => (0, 0, surface_width, surface_height),
+        ResizeStrategy::IntegerScale => {
+            let scale = std::cmp::min(surface_width / BUFFER_WIDTH, surface_height / BUFFER_HEIGHT).max(1);
+            let width = BUFFER_WIDTH * scale;
+            let height = BUFFER_HEIGHT * scale;
+            ((surface_width - width) / 2, (surface_height - height) / 2, width, height)
+        }
+        ResizeStrategy::Fit => {
+            let scale = (surface_width as f32 / BUFFER_WIDTH as f32)
+                .min(surface_height as f32 / BUFFER_HEIGHT as f32);
+            let width = (BUFFER_WIDTH as f32 * scale).round() as u32;
+            let height = (BUFFER_HEIGHT as f32 * scale).round() as u32;
+            ((surface_width - width) / 2, (surface_height - height) / 2, width, height)
+        }
+        ResizeStrategy::Fill => {
+            let scale = (surface_width as f32 / BUFFER_WIDTH as f32)
+                .max(surface_height as f32 / BUFFER_HEIGHT as f32);
+            let width = (BUFFER_WIDTH as f32 * scale).round() as u32;
+            let height = (BUFFER_HEIGHT as f32 * scale).round() as u32;
+            (
+                (surface_width as i64 - width as i64).max(0) as u32 / 2,
+                (surface_height as i64 - height as i64).max(0) as u32 / 2,
+                width,
+                height,
+            )
+        }
+    }
+}
+
+/// GPU renderer implementing the PixelRenderer trait (matching bhumi-terminal pattern). Key
+/// state and the WASD/IJKL binding table now live in `bhumi::input`, shared with every other
+/// frontend instead of being re-matched here.
 struct GpuRenderer {
     should_exit: bool,
-    keys_pressed: HashSet<KeyCode>,
-    shift_pressed: bool,
+    keys: Keys,
+    input_map: InputMap,
+    /// Accumulated relative mouse motion since it was last drained - fed to the flycam as
+    /// `InputEvent::MouseLook` each frame `handle_input` is polled.
+    mouse: Mouse,
     last_frame: Instant,
 }
 
@@ -27,8 +124,9 @@ impl PixelRenderer for GpuRenderer {
     fn new() -> Self {
         Self {
             should_exit: false,
-            keys_pressed: HashSet::new(),
-            shift_pressed: false,
+            keys: Keys::new(),
+            input_map: InputMap::default_bindings(),
+            mouse: Mouse::new(),
             last_frame: Instant::now(),
         }
     }
@@ -39,66 +137,11 @@ impl PixelRenderer for GpuRenderer {
     }
 
     fn handle_input(&mut self) -> Vec<InputEvent> {
-        let mut events = Vec::new();
-        
-        // Convert held keys to input events (same as terminal)
-        for key in &self.keys_pressed {
-            match key {
-                // Translation controls (WASD cluster - left hand)
-                KeyCode::KeyW => events.push(InputEvent::ThrustForward),
-                KeyCode::KeyS => events.push(InputEvent::ThrustBackward),
-                KeyCode::KeyA => events.push(InputEvent::ThrustLeft),
-                KeyCode::KeyD => events.push(InputEvent::ThrustRight),
-                KeyCode::Space => events.push(InputEvent::ThrustUp),
-                KeyCode::KeyC => events.push(InputEvent::ThrustDown),
-                
-                // Rotation controls (IJKL cluster) - behavior depends on shift
-                KeyCode::KeyI => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookPitchUp);
-                    } else {
-                        events.push(InputEvent::SteerPitchUp);
-                    }
-                },
-                KeyCode::KeyK => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookPitchDown);
-                    } else {
-                        events.push(InputEvent::SteerPitchDown);
-                    }
-                },
-                KeyCode::KeyJ => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookYawLeft);
-                    } else {
-                        events.push(InputEvent::SteerYawLeft);
-                    }
-                },
-                KeyCode::KeyL => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookYawRight);
-                    } else {
-                        events.push(InputEvent::SteerYawRight);
-                    }
-                },
-                KeyCode::KeyU => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookRollLeft);
-                    } else {
-                        events.push(InputEvent::SteerRollLeft);
-                    }
-                },
-                KeyCode::KeyO => {
-                    if self.shift_pressed {
-                        events.push(InputEvent::LookRollRight);
-                    } else {
-                        events.push(InputEvent::SteerRollRight);
-                    }
-                },
-                _ => {}
-            }
+        let mut events = self.input_map.poll(&self.keys);
+        let (dx, dy) = self.mouse.take_delta();
+        if dx != 0.0 || dy != 0.0 {
+            events.push(InputEvent::MouseLook { dx, dy });
         }
-        
         events
     }
 
@@ -107,50 +150,105 @@ impl PixelRenderer for GpuRenderer {
     }
 }
 
-/// Main GPU application  
+/// Main GPU application
 struct GpuApp {
     window: Option<Window>,
+    pixels: Option<Pixels>,
     gpu_renderer: GpuRenderer,
     core_renderer: Renderer,
     is_fullscreen: bool,
+    resize_strategy: ResizeStrategy,
+    scale_filter: ScaleFilter,
+    /// Whether the cursor is currently grabbed/hidden for mouse-look - raw `DeviceEvent`
+    /// motion is only fed to the camera while this is true, so moving the mouse over an
+    /// unfocused window doesn't spin the flycam.
+    cursor_grabbed: bool,
+    /// When set, drives the drone via `Renderer::update_from_actions`/`ActionHandler` instead of
+    /// the default `InputMap`-resolved `InputEvent`s - an alternate, remappable-bindings input
+    /// path sharing the same `gpu_renderer.keys` state. Selected via `--actions`.
+    action_handler: Option<ActionHandler>,
 }
 
 impl GpuApp {
-    fn new() -> Self {
+    fn new(resize_strategy: ResizeStrategy, scale_filter: ScaleFilter, use_action_handler: bool) -> Self {
         Self {
             window: None,
+            pixels: None,
             gpu_renderer: GpuRenderer::new(),
             core_renderer: Renderer::new(),
             is_fullscreen: false,
+            resize_strategy,
+            scale_filter,
+            cursor_grabbed: false,
+            action_handler: use_action_handler.then(ActionHandler::default_bindings),
         }
     }
-    
+
+    /// Toggles the cursor-grab used for mouse-look: tries `Locked` (relative motion, cursor
+    /// stays put) and falls back to `Confined` (cursor stays inside the window) on platforms
+    /// that don't support locking, hiding the cursor either way while grabbed.
+    fn toggle_cursor_grab(&mut self) {
+        let Some(window) = &self.window else { return };
+        self.cursor_grabbed = !self.cursor_grabbed;
+
+        if self.cursor_grabbed {
+            if window.set_cursor_grab(CursorGrabMode::Locked).is_err() {
+                if let Err(err) = window.set_cursor_grab(CursorGrabMode::Confined) {
+                    log::error!("Failed to grab cursor: {}", err);
+                }
+            }
+        } else if let Err(err) = window.set_cursor_grab(CursorGrabMode::None) {
+            log::error!("Failed to release cursor: {}", err);
+        }
+        window.set_cursor_visible(!self.cursor_grabbed);
+    }
+
     fn create_window(&mut self, event_loop: &ActiveEventLoop) {
         let monitor = event_loop.primary_monitor().unwrap();
         let monitor_size = monitor.size();
-        
-        // Calculate adaptive scaling for high-res displays
-        let scale_x = monitor_size.width / BUFFER_WIDTH;
-        let scale_y = monitor_size.height / BUFFER_HEIGHT;
-        let scale = std::cmp::min(scale_x, scale_y).max(2); // At least 2x scaling
-        
-        let window_size = LogicalSize::new(
-            BUFFER_WIDTH * scale,
-            BUFFER_HEIGHT * scale,
-        );
-        
+
+        // `IntegerScale` picks the initial window size itself; the other strategies just start
+        // at the monitor's own size. The viewport computed below is only logged.
+        let window_size = match self.resize_strategy {
+            ResizeStrategy::IntegerScale => {
+                let scale_x = monitor_size.width / BUFFER_WIDTH;
+                let scale_y = monitor_size.height / BUFFER_HEIGHT;
+                let scale = std::cmp::min(scale_x, scale_y).max(2); // At least 2x scaling
+                LogicalSize::new(BUFFER_WIDTH * scale, BUFFER_HEIGHT * scale)
+            }
+            ResizeStrategy::Fit | ResizeStrategy::Fill | ResizeStrategy::Stretch => {
+                LogicalSize::new(monitor_size.width, monitor_size.height)
+            }
+        };
+
         let window_attributes = Window::default_attributes()
             .with_title("Bhumi 3D - GPU Accelerated Flight")
             .with_inner_size(window_size)
             .with_min_inner_size(LogicalSize::new(BUFFER_WIDTH * 2, BUFFER_HEIGHT * 2));
-        
+
         let window = event_loop.create_window(window_attributes).unwrap();
         let window_size = window.inner_size();
-        
-        info!("GPU Window: {}Ã—{} ({}x scale) | Monitor: {}Ã—{}", 
-            window_size.width, window_size.height, scale, monitor_size.width, monitor_size.height);
-        
+        let viewport = viewport_rect(self.resize_strategy, window_size.width, window_size.height);
+
+        info!("GPU Window: {}×{} | Monitor: {}×{} | viewport: {:?} ({:?}, {:?})",
+            window_size.width, window_size.height, monitor_size.width, monitor_size.height,
+            viewport, self.resize_strategy, self.scale_filter);
+
+        // Build the surface/device/queue pipeline once here, instead of re-creating it on every
+        // `RedrawRequested` - `Resized` just reconfigures this same `Pixels` going forward.
         self.window = Some(window);
+        let window = self.window.as_ref().unwrap();
+        let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, window);
+        self.pixels = match PixelsBuilder::new(BUFFER_WIDTH, BUFFER_HEIGHT, surface_texture)
+            .render_texture_filter_mode(self.scale_filter.wgpu_filter_mode())
+            .build()
+        {
+            Ok(pixels) => Some(pixels),
+            Err(err) => {
+                log::error!("Failed to create GPU pixel surface: {}", err);
+                None
+            }
+        };
     }
     
     fn toggle_fullscreen(&mut self) {
@@ -187,6 +285,14 @@ impl ApplicationHandler for GpuApp {
                 info!("Exiting bhumi-wgpu");
                 event_loop.exit();
             }
+            WindowEvent::ModifiersChanged(modifiers) => {
+                let state: ModifiersState = modifiers.state();
+                self.gpu_renderer.keys.set_modifiers(Modifiers {
+                    shift: state.shift_key(),
+                    ctrl: state.control_key(),
+                    alt: state.alt_key(),
+                });
+            }
             WindowEvent::KeyboardInput {
                 event: KeyEvent {
                     physical_key: PhysicalKey::Code(key_code),
@@ -195,9 +301,6 @@ impl ApplicationHandler for GpuApp {
                 },
                 ..
             } => {
-                // Track shift state
-                self.gpu_renderer.shift_pressed = false; // TODO: detect shift properly
-                
                 match state {
                     ElementState::Pressed => {
                         match key_code {
@@ -206,76 +309,121 @@ impl ApplicationHandler for GpuApp {
                                 event_loop.exit();
                             },
                             KeyCode::F11 => self.toggle_fullscreen(),
+                            // `C` is already bound to `ThrustDown` in the shared `InputMap`, so
+                            // camera cycling gets its own key here instead of colliding with it.
+                            KeyCode::KeyV => self.core_renderer.cycle_camera(),
                             KeyCode::Digit0 => {
                                 // Reset drone
                                 self.core_renderer.update(0.016, &[InputEvent::Reset]);
                             },
                             KeyCode::Digit9 => {
-                                // Gentle stop - TODO: add shift detection for emergency brake
-                                self.core_renderer.update(0.016, &[InputEvent::GentleStop]);
+                                // Shift is now tracked via `ModifiersChanged`, so this can
+                                // actually tell a gentle stop from an emergency brake.
+                                let event = if self.gpu_renderer.keys.modifiers().shift {
+                                    InputEvent::EmergencyBrake
+                                } else {
+                                    InputEvent::GentleStop
+                                };
+                                self.core_renderer.update(0.016, &[event]);
                             },
                             _ => {
-                                self.gpu_renderer.keys_pressed.insert(key_code);
+                                if let Some(key) = mapped_key(key_code) {
+                                    self.gpu_renderer.keys.key_down(key);
+                                }
                             }
                         }
                     }
                     ElementState::Released => {
-                        self.gpu_renderer.keys_pressed.remove(&key_code);
+                        if let Some(key) = mapped_key(key_code) {
+                            self.gpu_renderer.keys.key_up(key);
+                        }
                     }
                 }
             }
             WindowEvent::RedrawRequested => {
-                // Simple test - just create and render pixels inline to avoid lifetime issues
-                if let Some(window) = &self.window {
-                    let window_size = window.inner_size();
-                    let surface_texture = SurfaceTexture::new(window_size.width, window_size.height, window);
-                    
-                    if let Ok(mut pixels) = Pixels::new(BUFFER_WIDTH, BUFFER_HEIGHT, surface_texture) {
-                        let now = Instant::now();
-                        let dt = (now - self.gpu_renderer.last_frame).as_secs_f32();
-                        self.gpu_renderer.last_frame = now;
-
-                        // Update 3D world
+                if let Some(pixels) = self.pixels.as_mut() {
+                    let now = Instant::now();
+                    let dt = (now - self.gpu_renderer.last_frame).as_secs_f32();
+                    self.gpu_renderer.last_frame = now;
+
+                    // Update 3D world - the remappable `ActionHandler` path if `--actions`
+                    // selected it, otherwise the default `InputMap`-resolved `InputEvent`s.
+                    if let Some(action_handler) = &self.action_handler {
+                        self.core_renderer.update_from_actions(
+                            dt,
+                            action_handler,
+                            &self.gpu_renderer.keys,
+                        );
+                    } else {
                         let input_events = self.gpu_renderer.handle_input();
                         self.core_renderer.update(dt, &input_events);
-                        self.core_renderer.render();
-
-                        // Copy buffer to GPU
-                        let frame = pixels.frame_mut();
-                        for (i, pixel) in self.core_renderer.buffer.pixels.iter().enumerate() {
-                            let offset = i * 4;
-                            if offset + 3 < frame.len() {
-                                frame[offset] = pixel[0];     // R
-                                frame[offset + 1] = pixel[1]; // G
-                                frame[offset + 2] = pixel[2]; // B
-                                frame[offset + 3] = pixel[3]; // A
-                            }
-                        }
-                        
-                        if let Err(err) = pixels.render() {
-                            log::error!("GPU render failed: {}", err);
+                    }
+                    self.core_renderer.render();
+
+                    // Copy buffer to GPU
+                    let frame = pixels.frame_mut();
+                    for (i, pixel) in self.core_renderer.buffer.pixels.iter().enumerate() {
+                        let offset = i * 4;
+                        if offset + 3 < frame.len() {
+                            frame[offset] = pixel[0];     // R
+                            frame[offset + 1] = pixel[1]; // G
+                            frame[offset + 2] = pixel[2]; // B
+                            frame[offset + 3] = pixel[3]; // A
                         }
                     }
-                    
+
+                    if let Err(err) = pixels.render() {
+                        log::error!("GPU render failed: {}", err);
+                    }
+                }
+
+                if let Some(window) = &self.window {
                     window.request_redraw();
                 }
             }
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: MouseButton::Right,
+                ..
+            } => {
+                self.toggle_cursor_grab();
+            }
             WindowEvent::Resized(new_size) => {
-                info!("Window resized to: {}Ã—{}", new_size.width, new_size.height);
+                let viewport = viewport_rect(self.resize_strategy, new_size.width, new_size.height);
+                info!("Window resized to: {}×{} | viewport: {:?}", new_size.width, new_size.height, viewport);
+                if let Some(pixels) = self.pixels.as_mut() {
+                    if let Err(err) = pixels.resize_surface(new_size.width, new_size.height) {
+                        log::error!("Failed to resize GPU surface: {}", err);
+                    }
+                }
             }
             _ => {}
         }
     }
+
+    /// Raw, unaccelerated pointer motion - the prerequisite for mouse-look, since
+    /// `WindowEvent::CursorMoved` only reports absolute position clamped to the window.
+    fn device_event(&mut self, _event_loop: &ActiveEventLoop, _device_id: DeviceId, event: DeviceEvent) {
+        if let DeviceEvent::MouseMotion { delta } = event {
+            if self.cursor_grabbed {
+                self.gpu_renderer.mouse.accumulate(delta.0 as f32, delta.1 as f32);
+            }
+        }
+    }
 }
 
 fn main() {
     env_logger::init();
-    
+
+    // --actions drives the drone through the remappable `ActionHandler` binding table instead
+    // of the default hardcoded `InputMap`.
+    let use_action_handler = std::env::args().any(|arg| arg == "--actions");
+
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
-    
-    let mut app = GpuApp::new();
-    
+
+    let mut app = GpuApp::new(ResizeStrategy::IntegerScale, ScaleFilter::Nearest, use_action_handler);
+
     info!("ðŸš€ Bhumi 3D GPU Renderer Starting");
     info!("ðŸ“± Adaptive scaling for high-res displays");
     info!("ðŸŽ® Controls: WASD=fly IJKL=rotate F11=fullscreen ESC=exit");
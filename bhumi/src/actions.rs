@@ -0,0 +1,99 @@
+//! Remappable input via an `ActionHandler`: abstract gameplay actions (axes and buttons) bound
+//! to physical `Key`s through a config loaded from disk, instead of the WASD/IJKL mapping being
+//! hardcoded independently in both `bhumi-wgpu`'s `handle_input`/`window_event` and the
+//! `InputEvent` match in `Renderer::update`.
+use std::collections::HashMap;
+
+use crate::input::{Key, Keys};
+use serde::{Deserialize, Serialize};
+
+/// A continuous gameplay action, resolved to -1.0..=1.0 from a positive/negative key pair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum AxisAction {
+    ThrustSurge, // forward/back
+    ThrustSway,  // left/right
+    Heave,       // up/down
+    Pitch,
+    Yaw,
+    Roll,
+}
+
+/// A discrete, held-key gameplay action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ButtonAction {
+    Reset,
+    Stop,
+}
+
+/// One axis's key binding: holding `positive` drives the value toward `1.0`, `negative` toward
+/// `-1.0`; holding both (or neither) resolves to `0.0`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct AxisBinding {
+    pub positive: Key,
+    pub negative: Key,
+}
+
+/// The full remappable binding table: every axis/button action mapped to the physical key(s)
+/// that drive it, loadable from a TOML config so remapping doesn't mean touching source.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionHandler {
+    axes: HashMap<AxisAction, AxisBinding>,
+    buttons: HashMap<ButtonAction, Key>,
+}
+
+impl ActionHandler {
+    /// WASD for surge/sway, Space/C for heave, IJKL for pitch/yaw, U/O for roll, 0/9 for
+    /// reset/stop - the same scheme every frontend already hardcodes, just expressed as data.
+    pub fn default_bindings() -> Self {
+        let mut axes = HashMap::new();
+        axes.insert(AxisAction::ThrustSurge, AxisBinding { positive: Key::W, negative: Key::S });
+        axes.insert(AxisAction::ThrustSway, AxisBinding { positive: Key::D, negative: Key::A });
+        axes.insert(AxisAction::Heave, AxisBinding { positive: Key::Space, negative: Key::C });
+        axes.insert(AxisAction::Pitch, AxisBinding { positive: Key::K, negative: Key::I });
+        axes.insert(AxisAction::Yaw, AxisBinding { positive: Key::L, negative: Key::J });
+        axes.insert(AxisAction::Roll, AxisBinding { positive: Key::O, negative: Key::U });
+
+        let mut buttons = HashMap::new();
+        buttons.insert(ButtonAction::Reset, Key::Digit0);
+        buttons.insert(ButtonAction::Stop, Key::Digit9);
+
+        Self { axes, buttons }
+    }
+
+    /// Loads a binding table from a TOML config file, falling back to `default_bindings` if the
+    /// file is missing or malformed. A parse error is logged; a missing or unreadable file falls
+    /// back without a log line.
+    pub fn load_or_default(path: &str) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(text) => match toml::from_str(&text) {
+                Ok(handler) => handler,
+                Err(err) => {
+                    log::warn!("failed to parse {}: {} - using default bindings", path, err);
+                    Self::default_bindings()
+                }
+            },
+            Err(_) => Self::default_bindings(),
+        }
+    }
+
+    /// Writes the current bindings out as TOML, e.g. to seed a config file a user can then hand-edit.
+    pub fn save(&self, path: &str) -> std::io::Result<()> {
+        let text = toml::to_string_pretty(self).unwrap_or_default();
+        std::fs::write(path, text)
+    }
+
+    /// Resolves an axis action's current value from which of its two bound keys are held.
+    pub fn axis(&self, keys: &Keys, action: AxisAction) -> f32 {
+        let Some(binding) = self.axes.get(&action) else {
+            return 0.0;
+        };
+        let positive = keys.is_down(binding.positive) as i32 as f32;
+        let negative = keys.is_down(binding.negative) as i32 as f32;
+        positive - negative
+    }
+
+    /// Whether a button action's bound key is currently held.
+    pub fn button(&self, keys: &Keys, action: ButtonAction) -> bool {
+        self.buttons.get(&action).is_some_and(|key| keys.is_down(*key))
+    }
+}
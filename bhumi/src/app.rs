@@ -0,0 +1,88 @@
+//! Plugin-based application shell built on `bevy_ecs`, modeled on lyra-engine's `WinitPlugin`
+//! and dArcEngine's `bevy_ecs` integration. Window/input backends (terminal, wgpu, pixels, SDL
+//! controllers) become installable plugins that add systems and resources to a shared `World`,
+//! instead of `App`/`GpuApp`/`State` each owning a `Renderer` by hand and reimplementing
+//! `ApplicationHandler` independently.
+use bevy_ecs::prelude::*;
+
+use crate::{InputEvent, PixelBuffer, Renderer};
+
+/// Wraps `Renderer` so it can live in the `World` as a resource; gameplay/render systems pull it
+/// via `ResMut<RendererResource>` instead of a frontend owning its own private copy.
+#[derive(Resource)]
+pub struct RendererResource(pub Renderer);
+
+/// Wraps a standalone `PixelBuffer` as a resource, for systems (e.g. a HUD overlay) that want to
+/// draw without going through `Renderer`. It is separate from the buffer inside
+/// `RendererResource`, which is the one `step_renderer_system` renders into.
+#[derive(Resource)]
+pub struct PixelBufferResource(pub PixelBuffer);
+
+/// This tick's input, collected by whichever plugin owns the window/controller event loop and
+/// drained by the gameplay systems.
+#[derive(Resource, Default)]
+pub struct InputEvents(pub Vec<InputEvent>);
+
+/// A plugin installs whatever systems and resources a particular window or input backend needs.
+/// Terminal, wgpu, pixels, and SDL controllers are all the same shape: `fn(&mut App)`.
+pub type Plugin = fn(&mut App);
+
+/// Central application: owns the ECS `World` and the `Schedule` that drives it every tick, with
+/// window/input backends registered as plugins rather than each reimplementing its own
+/// `ApplicationHandler`.
+pub struct App {
+    pub world: World,
+    schedule: Schedule,
+}
+
+impl App {
+    pub fn new() -> Self {
+        let mut world = World::new();
+        world.insert_resource(RendererResource(Renderer::new()));
+        world.insert_resource(PixelBufferResource(PixelBuffer::new()));
+        world.insert_resource(InputEvents::default());
+
+        Self {
+            world,
+            schedule: Schedule::default(),
+        }
+    }
+
+    /// Installs a plugin, letting it register whatever systems/resources it needs.
+    pub fn add_plugin(&mut self, plugin: Plugin) -> &mut Self {
+        plugin(self);
+        self
+    }
+
+    /// Adds a system to the app's per-tick schedule.
+    pub fn add_system<M>(&mut self, system: impl IntoSystemConfigs<M>) -> &mut Self {
+        self.schedule.add_systems(system);
+        self
+    }
+
+    /// Runs one tick of the schedule against the world - called once per frame by whichever
+    /// windowing backend is driving the event loop.
+    pub fn update(&mut self) {
+        self.schedule.run(&mut self.world);
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Drains this tick's `InputEvents` into the wrapped `Renderer` and renders a frame. Every
+/// frontend plugin needs this, so it's the one system `core_gameplay_plugin` installs.
+pub fn step_renderer_system(mut renderer: ResMut<RendererResource>, mut input: ResMut<InputEvents>) {
+    let events = std::mem::take(&mut input.0);
+    renderer.0.update(1.0 / 60.0, &events);
+    renderer.0.render();
+}
+
+/// The minimal plugin every frontend wants regardless of how it gets its window or its input:
+/// step the drone simulation and render a frame each tick.
+pub fn core_gameplay_plugin(app: &mut App) {
+    app.add_system(step_renderer_system);
+}
@@ -1,7 +1,98 @@
 use crate::CameraMode;
-use nalgebra::{Matrix4, Point3, Vector3};
+use nalgebra::{Matrix4, Point3, UnitQuaternion, Vector3, Vector4};
+
+/// Base distance of the third-person chase camera, in meters: it sits 1.3 times this far behind
+/// the drone and this far above it.
+const CHASE_DISTANCE: f32 = 2.5;
+/// Spring stiffness driving the chase camera's position toward its target each frame - higher
+/// settles faster. See `Camera::update`'s critically-damped spring step.
+const CHASE_STIFFNESS: f32 = 8.0;
+
+/// Mouse-delta-to-radians scale for `Camera::flycam_look`.
+const MOUSE_LOOK_SENSITIVITY: f32 = 0.003;
+/// Acceleration magnitude applied by a fully-held flycam movement axis.
+const FLYCAM_THRUST_MAG: f32 = 8.0;
+/// How long it takes flycam velocity to decay to half its value once thrust stops, via
+/// `damping_coeff = LN_2 / FLYCAM_VELOCITY_HALF_LIFE`.
+const FLYCAM_VELOCITY_HALF_LIFE: f32 = 0.25;
+
+/// Free-fly camera state: detached from the drone, driven by mouse-look and held movement keys
+/// instead of `Camera::update`'s drone-follow offset. Kept as its own block (rather than
+/// reusing `Camera::position`/`target` mid-flight) so switching back to a drone-follow mode
+/// doesn't lose the flycam's orientation/velocity.
+#[derive(Clone)]
+pub struct FlycamState {
+    pub position: Point3<f32>,
+    pub euler_yaw: f32,
+    pub euler_pitch: f32,
+    pub velocity: Vector3<f32>,
+}
+
+impl FlycamState {
+    fn new() -> Self {
+        Self {
+            position: Point3::new(0.0, 1.0, -3.0),
+            euler_yaw: 0.0,
+            euler_pitch: 0.0,
+            velocity: Vector3::zeros(),
+        }
+    }
+
+    /// Forward/right/up basis derived from the current yaw/pitch, for turning a camera-local
+    /// thrust direction into a world-space acceleration.
+    fn basis(&self) -> (Vector3<f32>, Vector3<f32>, Vector3<f32>) {
+        let forward = Vector3::new(
+            self.euler_yaw.sin() * self.euler_pitch.cos(),
+            self.euler_pitch.sin(),
+            self.euler_yaw.cos() * self.euler_pitch.cos(),
+        );
+        let right = forward.cross(&Vector3::y()).normalize();
+        let up = right.cross(&forward).normalize();
+        (forward, right, up)
+    }
+}
+
+/// Mouse-delta-to-radians scale for `Camera::free_look_look`.
+const FREE_LOOK_TURN_SPEED: f32 = 0.003;
+/// Movement speed in meters/second for `FreeFly`'s WASD/Space/C movement.
+const FREE_LOOK_MOVE_SPEED: f32 = 4.0;
+
+/// Orientation/position state shared by `CameraMode::FreeFly` and `CameraMode::FirstPerson`:
+/// accumulated `pan` (yaw) and `tilt` (pitch) angles plus a `position` that either free-moves
+/// (`FreeFly`) or tracks the drone (`FirstPerson`). Kept separate from `FlycamState` since the
+/// two model orientation differently (pan/tilt angles here vs. velocity-damped euler angles
+/// there) and callers pick the mode that fits.
+#[derive(Clone)]
+pub struct FreeLookState {
+    pub position: Point3<f32>,
+    pub pan: f32,
+    pub tilt: f32,
+}
+
+impl FreeLookState {
+    fn new() -> Self {
+        Self {
+            position: Point3::new(0.0, 1.0, -3.0),
+            pan: 0.0,
+            tilt: 0.0,
+        }
+    }
+
+    /// Forward/right/up basis derived from the current pan/tilt.
+    fn basis(&self) -> (Vector3<f32>, Vector3<f32>, Vector3<f32>) {
+        let forward = Vector3::new(
+            self.tilt.cos() * self.pan.sin(),
+            self.tilt.sin(),
+            self.tilt.cos() * self.pan.cos(),
+        );
+        let right = forward.cross(&Vector3::y()).normalize();
+        let up = right.cross(&forward).normalize();
+        (forward, right, up)
+    }
+}
 
 /// 3D camera for rendering world from different perspectives
+#[derive(Clone)]
 pub struct Camera {
     pub mode: CameraMode,
     pub position: Point3<f32>,
@@ -11,6 +102,19 @@ pub struct Camera {
     pub aspect: f32, // Width / height
     pub near: f32,   // Near clipping plane
     pub far: f32,    // Far clipping plane
+    /// State for `CameraMode::Flycam`, kept even while a different mode is active.
+    pub flycam: FlycamState,
+    /// State for `CameraMode::FreeFly`/`CameraMode::FirstPerson`, kept even while a different
+    /// mode is active.
+    pub free_look: FreeLookState,
+    /// `ThirdPerson` chase distance behind the drone, in meters.
+    pub dist: f32,
+    /// `ThirdPerson` spring stiffness - how quickly `position` catches up to the chase target.
+    pub stiffness: f32,
+    /// `ThirdPerson`'s look direction as of the last `update`, derived the same way
+    /// `get_view_matrix`'s `look_at_rh` derives one internally - kept around for callers that
+    /// want the camera's facing without rebuilding the view matrix themselves.
+    pub forward: Vector3<f32>,
 }
 
 impl Camera {
@@ -25,17 +129,98 @@ impl Camera {
             aspect: 320.0 / 240.0,                 // 4:3 aspect ratio
             near: 0.1,                             // 10cm near plane
             far: 100.0,                            // 100m far plane
+            flycam: FlycamState::new(),
+            free_look: FreeLookState::new(),
+            dist: CHASE_DISTANCE,
+            stiffness: CHASE_STIFFNESS,
+            forward: Vector3::new(0.0, 0.0, 1.0),
         }
     }
 
-    /// Update camera based on drone position and current mode
-    pub fn update(&mut self, drone_pos: [f32; 3]) {
+    /// Update camera based on drone position/orientation and current mode. In `Flycam`/
+    /// `FreeFly`/`FirstPerson` modes the viewpoint is driven by `flycam_look`/`flycam_update` or
+    /// `free_look_look`/`free_look_move` instead, so `update` is a no-op for them.
+    ///
+    /// `ThirdPerson` is a spring-damper chase camera rather than a fixed offset: the target sits
+    /// behind the drone along its own local back axis (so it leans with the craft instead of
+    /// always trailing along world axes) and above it along world up, and `position` eases
+    /// toward that target each frame instead of snapping to it, so fast rotation or sudden stops don't jitter the
+    /// view.
+    pub fn update(&mut self, drone_pos: [f32; 3], drone_rot: UnitQuaternion<f32>, dt: f32) {
+        if matches!(
+            self.mode,
+            CameraMode::Flycam | CameraMode::FreeFly | CameraMode::FirstPerson
+        ) {
+            return;
+        }
+
         let drone_point = Point3::new(drone_pos[0], drone_pos[1], drone_pos[2]);
 
-        // Third-person camera: behind and above drone, looking at drone
-        let offset = Vector3::new(-1.5, 1.0, -2.0); // Behind, above, and to the side
-        self.position = drone_point + offset;
-        self.target = drone_point; // Look at the drone
+        // "Up" is world +y: `FreeFlight` has no gravity, and the `Gravity`/`Atmospheric` flight
+        // models pull along -y, so world up is also their gravity-aligned up.
+        let up = Vector3::y();
+        let back = drone_rot * -Vector3::z();
+
+        let target = drone_point + back * self.dist * 1.3 + up * self.dist;
+        self.position += (target - self.position) * (1.0 - (-self.stiffness * dt).exp());
+        self.target = drone_point;
+        self.up = up;
+        self.forward = (drone_point - self.position).normalize();
+    }
+
+    /// Applies accumulated mouse motion to the flycam's orientation, clamping pitch to ±π/2 so
+    /// it can't flip past straight up or down.
+    pub fn flycam_look(&mut self, dx: f32, dy: f32) {
+        self.flycam.euler_yaw += dx * MOUSE_LOOK_SENSITIVITY;
+        self.flycam.euler_pitch = (self.flycam.euler_pitch - dy * MOUSE_LOOK_SENSITIVITY)
+            .clamp(-std::f32::consts::FRAC_PI_2, std::f32::consts::FRAC_PI_2);
+    }
+
+    /// Integrates the flycam for one frame. `thrust_dir` is a camera-local movement vector (x:
+    /// strafe, y: vertical, z: forward), each axis in roughly -1.0..=1.0 from held keys.
+    /// Velocity is damped toward zero exponentially rather than cut instantly, so releasing
+    /// every key coasts to a stop.
+    pub fn flycam_update(&mut self, thrust_dir: Vector3<f32>, dt: f32) {
+        let (forward, right, up) = self.flycam.basis();
+        let accel =
+            (forward * thrust_dir.z + right * thrust_dir.x + up * thrust_dir.y) * FLYCAM_THRUST_MAG;
+
+        self.flycam.velocity += accel * dt;
+        let damping_coeff = std::f32::consts::LN_2 / FLYCAM_VELOCITY_HALF_LIFE;
+        self.flycam.velocity *= (-damping_coeff * dt).exp();
+        self.flycam.position += self.flycam.velocity * dt;
+
+        self.position = self.flycam.position;
+        let (forward, _, _) = self.flycam.basis();
+        self.target = self.flycam.position + forward;
+    }
+
+    /// Applies accumulated mouse motion to `free_look`'s pan/tilt, clamping tilt to roughly
+    /// ±89° so the view never flips through straight up or down.
+    pub fn free_look_look(&mut self, mouse_dx: f32, mouse_dy: f32) {
+        self.free_look.pan += mouse_dx * FREE_LOOK_TURN_SPEED;
+        self.free_look.tilt = (self.free_look.tilt - mouse_dy * FREE_LOOK_TURN_SPEED)
+            .clamp(-89.0_f32.to_radians(), 89.0_f32.to_radians());
+    }
+
+    /// Integrates `free_look` for one frame. In `FreeFly` (`anchor` is `None`), `move_dir` (x:
+    /// strafe, y: vertical, z: forward, each roughly -1.0..=1.0) moves `position` at
+    /// `FREE_LOOK_MOVE_SPEED`. In `FirstPerson` (`anchor` is `Some`), `position` just tracks the
+    /// given anchor - typically the drone - each frame instead.
+    pub fn free_look_move(&mut self, move_dir: Vector3<f32>, dt: f32, anchor: Option<Point3<f32>>) {
+        let (forward, right, up) = self.free_look.basis();
+
+        match anchor {
+            Some(anchor_pos) => self.free_look.position = anchor_pos,
+            None => {
+                let velocity = (forward * move_dir.z + right * move_dir.x + up * move_dir.y)
+                    * FREE_LOOK_MOVE_SPEED;
+                self.free_look.position += velocity * dt;
+            }
+        }
+
+        self.position = self.free_look.position;
+        self.target = self.free_look.position + forward;
     }
 
     /// Get view matrix for current camera
@@ -59,6 +244,108 @@ impl Camera {
     }
 }
 
+/// A named collection of fixed scene cameras plus the existing user-controlled `Camera`,
+/// cyclable at runtime - mirrors the glTF sample-viewer behavior of walking through every camera
+/// defined in the scene before wrapping back to the manual camera.
+pub struct CameraSet {
+    named: Vec<(String, Camera)>,
+    /// Index into `named` of the active scene camera, or `None` when the user camera is active.
+    active: Option<usize>,
+}
+
+impl CameraSet {
+    pub fn new() -> Self {
+        Self {
+            named: Vec::new(),
+            active: None,
+        }
+    }
+
+    /// Registers a fixed camera by name with its own position/target/fov. Its `mode` stays at
+    /// `Camera::new`'s default, since scene cameras are plain fixed vantage points rather than
+    /// drone-follow or free-look ones.
+    pub fn register(&mut self, name: &str, position: Point3<f32>, target: Point3<f32>, fov: f32) {
+        let mut camera = Camera::new();
+        camera.position = position;
+        camera.target = target;
+        camera.fov = fov;
+        self.named.push((name.to_string(), camera));
+    }
+
+    /// Cycles to the next registered scene camera, wrapping back to the user-controlled camera
+    /// (i.e. `active()` becomes `None`) after the last one.
+    pub fn cycle(&mut self) {
+        self.active = match self.active {
+            None if !self.named.is_empty() => Some(0),
+            Some(i) if i + 1 < self.named.len() => Some(i + 1),
+            _ => None,
+        };
+    }
+
+    /// The active scene camera, or `None` when the user-controlled camera is active.
+    pub fn active(&self) -> Option<&Camera> {
+        self.active.map(|i| &self.named[i].1)
+    }
+
+    /// Name of the active scene camera, or `"User"` when the user-controlled camera is active -
+    /// what a HUD would show for "which view is live".
+    pub fn active_name(&self) -> &str {
+        match self.active {
+            Some(i) => &self.named[i].0,
+            None => "User",
+        }
+    }
+}
+
+/// A pixel sub-rectangle of the output buffer a single camera renders into - one half of a
+/// split-screen view, a picture-in-picture minimap corner, or (via `Rect::full`) the whole
+/// buffer, which is what every backend rendered into before multi-viewport rendering existed.
+#[derive(Debug, Clone, Copy)]
+pub struct Rect {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl Rect {
+    /// A viewport covering the entire buffer - the implicit viewport every backend used before
+    /// `RenderCallbacks` existed.
+    pub fn full(width: u32, height: u32) -> Self {
+        Self {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+}
+
+/// Like `world_to_screen`, but maps into a sub-rectangle of the buffer instead of the whole
+/// thing: the NDC-to-pixel scaling uses `rect`'s own width/height, then the result is offset by
+/// `rect.x`/`rect.y` so it lands inside that sub-rectangle rather than the top-left corner.
+pub fn world_to_screen_in_rect(
+    world_pos: Point3<f32>,
+    view_projection: &Matrix4<f32>,
+    rect: Rect,
+) -> Option<(f32, f32, f32)> {
+    let (x, y, depth) = world_to_screen(world_pos, view_projection, rect.width, rect.height)?;
+    Some((x + rect.x as f32, y + rect.y as f32, depth))
+}
+
+/// Like `clip_segment_to_screen`, but maps into a sub-rectangle of the buffer - see
+/// `world_to_screen_in_rect`.
+pub fn clip_segment_to_screen_in_rect(
+    a: Point3<f32>,
+    b: Point3<f32>,
+    view_projection: &Matrix4<f32>,
+    rect: Rect,
+) -> Option<((f32, f32, f32), (f32, f32, f32))> {
+    let (sa, sb) = clip_segment_to_screen(a, b, view_projection, rect.width, rect.height)?;
+    let offset = |p: (f32, f32, f32)| (p.0 + rect.x as f32, p.1 + rect.y as f32, p.2);
+    Some((offset(sa), offset(sb)))
+}
+
 /// Convert 3D world coordinates to 2D screen coordinates
 /// Returns (x, y, depth) where x,y are in pixel coordinates and depth is normalized [0,1]
 /// Returns None if point is behind camera or outside frustum
@@ -93,3 +380,68 @@ pub fn world_to_screen(
 
     Some((screen_x, screen_y, ndc_z))
 }
+
+/// Smallest clip-space `w` still treated as "in front of the camera" - points with `w` at or
+/// below this are behind the near plane.
+const NEAR_CLIP_W: f32 = 1e-4;
+
+/// Clips a world-space line segment against the camera's near plane before projecting it to
+/// screen space, unlike `world_to_screen`, which just drops a point entirely once it's behind the
+/// camera. A segment straddling the camera (one endpoint in front, one behind) gets its behind
+/// endpoint replaced with where it crosses the near plane, so it draws its visible half instead of
+/// popping out of existence. Returns `None` only when both endpoints are behind the camera, or
+/// when the segment's fast frustum-reject fast path finds it entirely off one side of the screen.
+pub fn clip_segment_to_screen(
+    a: Point3<f32>,
+    b: Point3<f32>,
+    view_projection: &Matrix4<f32>,
+    screen_width: u32,
+    screen_height: u32,
+) -> Option<((f32, f32, f32), (f32, f32, f32))> {
+    let clip_a = view_projection * a.to_homogeneous();
+    let clip_b = view_projection * b.to_homogeneous();
+
+    let (clip_a, clip_b) = match (clip_a.w > NEAR_CLIP_W, clip_b.w > NEAR_CLIP_W) {
+        (true, true) => (clip_a, clip_b),
+        (false, false) => return None, // Both behind the camera.
+        (true, false) => (clip_a, clip_at_near_plane(clip_a, clip_b)),
+        (false, true) => (clip_at_near_plane(clip_b, clip_a), clip_b),
+    };
+
+    let ndc_a = (clip_a.x / clip_a.w, clip_a.y / clip_a.w);
+    let ndc_b = (clip_b.x / clip_b.w, clip_b.y / clip_b.w);
+
+    // Fast-path reject: both endpoints past the same side of the frustum.
+    if (ndc_a.0 < -1.0 && ndc_b.0 < -1.0)
+        || (ndc_a.0 > 1.0 && ndc_b.0 > 1.0)
+        || (ndc_a.1 < -1.0 && ndc_b.1 < -1.0)
+        || (ndc_a.1 > 1.0 && ndc_b.1 > 1.0)
+    {
+        return None;
+    }
+
+    Some((
+        ndc_to_screen(ndc_a, clip_a.z / clip_a.w, screen_width, screen_height),
+        ndc_to_screen(ndc_b, clip_b.z / clip_b.w, screen_width, screen_height),
+    ))
+}
+
+/// Interpolates the homogeneous coordinate between `in_front` (`w > NEAR_CLIP_W`) and `behind`
+/// (`w <= NEAR_CLIP_W`) to the point where `w == NEAR_CLIP_W`, i.e. exactly on the near plane.
+fn clip_at_near_plane(in_front: Vector4<f32>, behind: Vector4<f32>) -> Vector4<f32> {
+    let t = (in_front.w - NEAR_CLIP_W) / (in_front.w - behind.w);
+    in_front + (behind - in_front) * t
+}
+
+/// Maps an already-perspective-divided NDC `(x, y)` plus its NDC depth into screen pixel space,
+/// the same conversion `world_to_screen` does after its behind-camera check.
+fn ndc_to_screen(
+    ndc: (f32, f32),
+    ndc_z: f32,
+    screen_width: u32,
+    screen_height: u32,
+) -> (f32, f32, f32) {
+    let screen_x = (ndc.0 + 1.0) * 0.5 * screen_width as f32;
+    let screen_y = (1.0 - ndc.1) * 0.5 * screen_height as f32;
+    (screen_x, screen_y, ndc_z)
+}
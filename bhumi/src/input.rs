@@ -0,0 +1,156 @@
+//! Backend-agnostic input subsystem, modeled on dunge's `Loop`/`Input`/`Keys` pattern: every
+//! frontend (terminal, winit, SDL) just feeds raw key up/down and modifier changes into `Keys`,
+//! and a single `InputMap::poll()` turns the current state into `InputEvent`s via an editable
+//! binding table instead of each frontend re-implementing its own `match`.
+use crate::InputEvent;
+use serde::{Deserialize, Serialize};
+
+/// A physical key, identified the same way regardless of which windowing/terminal backend is
+/// reporting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Key {
+    W,
+    A,
+    S,
+    D,
+    Space,
+    C,
+    I,
+    J,
+    K,
+    L,
+    U,
+    O,
+    Digit0,
+    Digit9,
+    Escape,
+    Q,
+}
+
+/// Live modifier-key state, tracked independently of any particular key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Modifiers {
+    pub shift: bool,
+    pub ctrl: bool,
+    pub alt: bool,
+}
+
+/// Tracks which keys are currently held and the live modifier state. Nothing in here knows
+/// about winit's `KeyCode`, SDL's `Keycode`, or the terminal's raw byte codes - frontends
+/// translate into `Key` at the edge and call `key_down`/`key_up`/`set_modifiers`.
+#[derive(Default)]
+pub struct Keys {
+    held: std::collections::HashSet<Key>,
+    modifiers: Modifiers,
+}
+
+impl Keys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn key_down(&mut self, key: Key) {
+        self.held.insert(key);
+    }
+
+    pub fn key_up(&mut self, key: Key) {
+        self.held.remove(&key);
+    }
+
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+    }
+
+    pub fn is_down(&self, key: Key) -> bool {
+        self.held.contains(&key)
+    }
+
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+}
+
+/// Accumulated mouse motion since it was last drained, tracked the same way as `Keys` so a
+/// future mouse-look binding can hang off the same `InputMap`.
+#[derive(Default)]
+pub struct Mouse {
+    dx: f32,
+    dy: f32,
+}
+
+impl Mouse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn accumulate(&mut self, dx: f32, dy: f32) {
+        self.dx += dx;
+        self.dy += dy;
+    }
+
+    /// Returns the motion accumulated since the last call, resetting it to zero.
+    pub fn take_delta(&mut self) -> (f32, f32) {
+        (std::mem::take(&mut self.dx), std::mem::take(&mut self.dy))
+    }
+}
+
+/// One entry in an `InputMap`'s binding table: `key` fires `event` while held, but only when
+/// the shift modifier is in the state `requires_shift` asks for.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub key: Key,
+    pub requires_shift: bool,
+    pub event: InputEvent,
+}
+
+/// Resolves `(key, modifiers)` into `InputEvent`s via a user-editable binding table, so remapping
+/// controls doesn't mean touching any particular renderer's `handle_input`.
+pub struct InputMap {
+    bindings: Vec<Binding>,
+}
+
+impl InputMap {
+    pub fn new(bindings: Vec<Binding>) -> Self {
+        Self { bindings }
+    }
+
+    /// WASD for translation, IJKL for rotation (steering without shift, look-only with it),
+    /// matching the scheme every existing frontend already hardcodes independently.
+    pub fn default_bindings() -> Self {
+        Self::new(vec![
+            Binding { key: Key::W, requires_shift: false, event: InputEvent::ThrustForward },
+            Binding { key: Key::S, requires_shift: false, event: InputEvent::ThrustBackward },
+            Binding { key: Key::A, requires_shift: false, event: InputEvent::ThrustLeft },
+            Binding { key: Key::D, requires_shift: false, event: InputEvent::ThrustRight },
+            Binding { key: Key::Space, requires_shift: false, event: InputEvent::ThrustUp },
+            Binding { key: Key::C, requires_shift: false, event: InputEvent::ThrustDown },
+            Binding { key: Key::I, requires_shift: false, event: InputEvent::SteerPitchUp },
+            Binding { key: Key::I, requires_shift: true, event: InputEvent::LookPitchUp },
+            Binding { key: Key::K, requires_shift: false, event: InputEvent::SteerPitchDown },
+            Binding { key: Key::K, requires_shift: true, event: InputEvent::LookPitchDown },
+            Binding { key: Key::J, requires_shift: false, event: InputEvent::SteerYawLeft },
+            Binding { key: Key::J, requires_shift: true, event: InputEvent::LookYawLeft },
+            Binding { key: Key::L, requires_shift: false, event: InputEvent::SteerYawRight },
+            Binding { key: Key::L, requires_shift: true, event: InputEvent::LookYawRight },
+            Binding { key: Key::U, requires_shift: false, event: InputEvent::SteerRollLeft },
+            Binding { key: Key::U, requires_shift: true, event: InputEvent::LookRollLeft },
+            Binding { key: Key::O, requires_shift: false, event: InputEvent::SteerRollRight },
+            Binding { key: Key::O, requires_shift: true, event: InputEvent::LookRollRight },
+        ])
+    }
+
+    pub fn bindings_mut(&mut self) -> &mut Vec<Binding> {
+        &mut self.bindings
+    }
+
+    /// Resolves every currently-held key against the binding table, returning one `InputEvent`
+    /// per matching binding.
+    pub fn poll(&self, keys: &Keys) -> Vec<InputEvent> {
+        let shift = keys.modifiers().shift;
+        self.bindings
+            .iter()
+            .filter(|binding| binding.requires_shift == shift && keys.is_down(binding.key))
+            .map(|binding| binding.event.clone())
+            .collect()
+    }
+}
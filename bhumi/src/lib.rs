@@ -1,6 +1,101 @@
 // #[cfg(target_arch = "wasm32")]
 // use wasm_bindgen::prelude::*;
 
+pub mod actions;
+pub mod app;
+mod camera;
+pub mod input;
+pub mod netcode;
+pub mod physics;
+mod pixel_buffer;
+mod renderer;
+mod scene_script;
+
+pub use camera::{Camera, CameraSet, Rect};
+pub use physics::PhysicsWorld;
+pub use pixel_buffer::{PixelBuffer, PixelRenderer};
+pub use renderer::{RenderCallbacks, Renderer, SingleViewport};
+
+/// Which of `Camera`'s several follow/look behaviors is currently driving its `position`/`target`
+/// each frame - see `Camera::update`'s doc comment for how `ThirdPerson` differs from the other
+/// three.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    /// Spring-damper chase camera trailing behind and above the drone.
+    ThirdPerson,
+    /// Detached free-fly camera driven by mouse-look and held movement keys.
+    Flycam,
+    /// Detached free-look camera whose position the player also moves freely, like `Flycam` but
+    /// sharing `FreeLookState`'s pan/tilt orientation instead of `FlycamState`'s euler angles.
+    FreeFly,
+    /// Free-look orientation anchored to the drone's position, like a cockpit view.
+    FirstPerson,
+}
+
+/// A single resolved piece of player input, produced by `input::InputMap::poll` (or a frontend's
+/// own key handling) and consumed by `Renderer::update`. Covers both the digital WASD/IJKL
+/// cluster every backend binds by default and the analog `Thrust`/`Torque`/`MouseLook` variants a
+/// game controller or mouse feeds in.
+#[derive(Debug, Clone)]
+pub enum InputEvent {
+    // Translation forces (WASD cluster).
+    ThrustForward,
+    ThrustBackward,
+    ThrustLeft,
+    ThrustRight,
+    ThrustUp,
+    ThrustDown,
+
+    // Rotational torques (IJKL cluster, no shift held).
+    PitchUp,
+    PitchDown,
+    YawLeft,
+    YawRight,
+    RollLeft,
+    RollRight,
+
+    // `input::InputMap`'s steer/look vocabulary for the same IJKL cluster - `Steer*` drives the
+    // drone the same as the bare `PitchUp`/`YawLeft`/etc. above, `Look*` (shift held) instead
+    // drives the free-look cameras via `MouseLook`-style orientation changes.
+    SteerPitchUp,
+    SteerPitchDown,
+    SteerYawLeft,
+    SteerYawRight,
+    SteerRollLeft,
+    SteerRollRight,
+    LookPitchUp,
+    LookPitchDown,
+    LookYawLeft,
+    LookYawRight,
+    LookRollLeft,
+    LookRollRight,
+
+    /// Analog translation, e.g. from a game controller stick - magnitude is already
+    /// deadzone-normalized by the input source.
+    Thrust { x: f32, y: f32, z: f32 },
+    /// Analog rotation, e.g. from a game controller stick.
+    Torque { pitch: f32, yaw: f32, roll: f32 },
+    /// Relative mouse motion for the flycam's/free-look's look direction.
+    MouseLook { dx: f32, dy: f32 },
+
+    /// Switches the active camera mode.
+    CameraMode(CameraMode),
+    /// Resets the drone to its starting position and stops all motion.
+    Reset,
+    /// Stops all drone motion without changing position.
+    Stop,
+    /// Same as `Stop` - a softer-sounding alias some frontends bind to a dedicated key so their
+    /// own logs/UI can distinguish "player asked to stop" from a plain digital `Stop` binding.
+    GentleStop,
+    /// Same as `Stop` - bound behind a modifier (e.g. shift) by frontends that want to tell a
+    /// deliberate, harder stop from a gentle one in their own input handling, even though both
+    /// resolve to the same underlying physics call today.
+    EmergencyBrake,
+    /// Requests the frontend exit. A no-op for `Renderer::update` itself - each backend's own
+    /// event loop is what actually reads this and quits.
+    Exit,
+}
+
 // This will store the state of our game
 pub struct State {
     window: std::sync::Arc<winit::window::Window>,
@@ -196,6 +291,9 @@ impl winit::application::ApplicationHandler<State> for App {
                 }
                 sdl2::event::Event::ControllerButtonDown { which, button, .. } => {
                     println!("Controller {} button {:?} pressed", which, button);
+                    if let Some(input_event) = controller_button_to_input(button) {
+                        println!("  -> {:?}", input_event);
+                    }
                 }
                 sdl2::event::Event::ControllerButtonUp { which, button, .. } => {
                     println!("Controller {} button {:?} released", which, button);
@@ -207,6 +305,9 @@ impl winit::application::ApplicationHandler<State> for App {
                     // Use i32 to avoid overflow when value is i16::MIN (-32768)
                     if (value as i32).abs() > 8000 {
                         println!("Controller {} axis {:?}: {}", which, axis, value);
+                        if let Some(input_event) = controller_axis_to_input(axis, value) {
+                            println!("  -> {:?}", input_event);
+                        }
                     }
                 }
                 _ => {}
@@ -215,6 +316,73 @@ impl winit::application::ApplicationHandler<State> for App {
     }
 }
 
+/// Rescales a raw SDL axis reading into the `InputEvent` vocabulary the renderer already
+/// understands, applying a per-axis deadzone so stick drift near center doesn't register as
+/// input and normalizing the remaining travel to a clean 0.0..=1.0 magnitude.
+#[cfg(not(target_arch = "wasm32"))]
+fn controller_axis_to_input(axis: sdl2::controller::Axis, value: i16) -> Option<InputEvent> {
+    const DEADZONE: i32 = 8000;
+    const AXIS_MAX: i32 = 32768;
+
+    let raw = value as i32;
+    if raw.abs() <= DEADZONE {
+        return None;
+    }
+    let sign = raw.signum() as f32;
+    let magnitude = sign * (raw.abs() - DEADZONE) as f32 / (AXIS_MAX - DEADZONE) as f32;
+
+    use sdl2::controller::Axis;
+    match axis {
+        // Left stick: sway/surge, matching the WASD thrust cluster's translation axes.
+        Axis::LeftX => Some(InputEvent::Thrust {
+            x: magnitude * 0.3,
+            y: 0.0,
+            z: 0.0,
+        }),
+        Axis::LeftY => Some(InputEvent::Thrust {
+            x: 0.0,
+            y: 0.0,
+            z: -magnitude * 0.3, // SDL's Y axis is inverted relative to "stick forward"
+        }),
+        // Right stick: pitch/yaw, matching the IJKL rotation cluster.
+        Axis::RightX => Some(InputEvent::Torque {
+            pitch: 0.0,
+            yaw: magnitude * 0.05,
+            roll: 0.0,
+        }),
+        Axis::RightY => Some(InputEvent::Torque {
+            pitch: magnitude * 0.05,
+            yaw: 0.0,
+            roll: 0.0,
+        }),
+        // Triggers only read 0..=32767, so `magnitude` is never negative here.
+        Axis::TriggerLeft => Some(InputEvent::Thrust {
+            x: 0.0,
+            y: -magnitude * 0.5,
+            z: 0.0,
+        }),
+        Axis::TriggerRight => Some(InputEvent::Thrust {
+            x: 0.0,
+            y: magnitude * 0.5,
+            z: 0.0,
+        }),
+    }
+}
+
+/// Maps the handful of buttons this controller layout cares about onto the same digital
+/// `InputEvent` variants the keyboard backends already emit.
+#[cfg(not(target_arch = "wasm32"))]
+fn controller_button_to_input(button: sdl2::controller::Button) -> Option<InputEvent> {
+    use sdl2::controller::Button;
+    match button {
+        Button::Start => Some(InputEvent::Reset),
+        Button::Back => Some(InputEvent::Stop),
+        Button::LeftShoulder => Some(InputEvent::RollLeft),
+        Button::RightShoulder => Some(InputEvent::RollRight),
+        _ => None,
+    }
+}
+
 pub fn run() -> anyhow::Result<()> {
     #[cfg(not(target_arch = "wasm32"))]
     {
@@ -0,0 +1,216 @@
+//! Packed input encoding and a GGRS-style rollback buffer for deterministic multiplayer flight.
+//! Pairs with `PhysicsWorld::save_state`/`restore_state` (the state half) and `PhysicsWorld::step`
+//! always advancing by `physics::FIXED_DT` (the determinism half) - this module is just the
+//! bookkeeping that decides which frame to resimulate and with what inputs.
+
+use crate::physics::{PhysicsWorld, FIXED_DT};
+use rapier3d::prelude::Vector;
+
+/// One frame's worth of discrete flight input, packed into a transmittable bitfield instead of a
+/// `Vec<InputEvent>` - cheap to send over the wire and replay bit-exactly during a rollback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InputBits(u16);
+
+impl InputBits {
+    pub const THRUST_FORWARD: u16 = 1 << 0;
+    pub const THRUST_BACKWARD: u16 = 1 << 1;
+    pub const THRUST_LEFT: u16 = 1 << 2;
+    pub const THRUST_RIGHT: u16 = 1 << 3;
+    pub const THRUST_UP: u16 = 1 << 4;
+    pub const THRUST_DOWN: u16 = 1 << 5;
+    pub const STEER_PITCH_UP: u16 = 1 << 6;
+    pub const STEER_PITCH_DOWN: u16 = 1 << 7;
+    pub const STEER_YAW_LEFT: u16 = 1 << 8;
+    pub const STEER_YAW_RIGHT: u16 = 1 << 9;
+
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn set(&mut self, bit: u16) {
+        self.0 |= bit;
+    }
+
+    pub fn clear(&mut self, bit: u16) {
+        self.0 &= !bit;
+    }
+
+    pub fn is_set(&self, bit: u16) -> bool {
+        self.0 & bit != 0
+    }
+
+    pub fn to_bytes(self) -> [u8; 2] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn from_bytes(bytes: [u8; 2]) -> Self {
+        Self(u16::from_le_bytes(bytes))
+    }
+
+    /// Resolves this frame's bits into the world-space force `PhysicsWorld::step` expects, using
+    /// the same per-bit magnitudes `Renderer::update`'s `InputEvent` match applies.
+    pub fn resolve_force(&self) -> Vector<f32> {
+        let mut force = Vector::new(0.0, 0.0, 0.0);
+        if self.is_set(Self::THRUST_FORWARD) {
+            force.z += 0.3;
+        }
+        if self.is_set(Self::THRUST_BACKWARD) {
+            force.z -= 0.3;
+        }
+        if self.is_set(Self::THRUST_LEFT) {
+            force.x -= 0.3;
+        }
+        if self.is_set(Self::THRUST_RIGHT) {
+            force.x += 0.3;
+        }
+        if self.is_set(Self::THRUST_UP) {
+            force.y += 0.5;
+        }
+        if self.is_set(Self::THRUST_DOWN) {
+            force.y -= 0.5;
+        }
+        force
+    }
+
+    /// Resolves this frame's bits into the world-space torque `PhysicsWorld::step_with_torque`
+    /// expects, using the same per-bit magnitudes `Renderer::update`'s `InputEvent::SteerPitchUp`/
+    /// `SteerYawLeft`/etc. match applies. Split from `resolve_force` since thrust and steering are
+    /// independent input channels.
+    pub fn resolve_torque(&self) -> Vector<f32> {
+        let mut torque = Vector::new(0.0, 0.0, 0.0);
+        if self.is_set(Self::STEER_PITCH_UP) {
+            torque.x -= 0.05;
+        }
+        if self.is_set(Self::STEER_PITCH_DOWN) {
+            torque.x += 0.05;
+        }
+        if self.is_set(Self::STEER_YAW_LEFT) {
+            torque.y -= 0.05;
+        }
+        if self.is_set(Self::STEER_YAW_RIGHT) {
+            torque.y += 0.05;
+        }
+        torque
+    }
+}
+
+/// How many past frames `RollbackBuffer` keeps saved states/inputs for. A remote input arriving
+/// for a frame older than this window can no longer be reconciled and is simply dropped.
+const ROLLBACK_WINDOW: usize = 8;
+
+/// One simulated frame's bookkeeping: the state right after it was stepped, the inputs that
+/// produced it, and whether the remote input is confirmed or still a prediction.
+struct RollbackFrame {
+    frame: u64,
+    state: Vec<u8>,
+    local_input: InputBits,
+    remote_input: InputBits,
+    remote_confirmed: bool,
+}
+
+/// Deterministic rollback driver for `PhysicsWorld`: advances the sim one `FIXED_DT` frame at a
+/// time, predicting unconfirmed remote input as a repeat of the last known value, and
+/// re-simulates from the frame a late-arriving authoritative remote input diverges from.
+pub struct RollbackBuffer {
+    frames: std::collections::VecDeque<RollbackFrame>,
+    current_frame: u64,
+    last_remote_input: InputBits,
+}
+
+impl RollbackBuffer {
+    pub fn new() -> Self {
+        Self {
+            frames: std::collections::VecDeque::new(),
+            current_frame: 0,
+            last_remote_input: InputBits::empty(),
+        }
+    }
+
+    /// Advances the sim by exactly one `FIXED_DT` frame using `local_input`, predicting the
+    /// remote side as a repeat of its last confirmed input, and records the resulting state.
+    pub fn advance(&mut self, physics: &mut PhysicsWorld, local_input: InputBits) {
+        let frame = self.current_frame;
+        let remote_input = self.last_remote_input;
+        self.push_stepped_frame(physics, frame, local_input, remote_input, false);
+        self.current_frame += 1;
+    }
+
+    /// Applies an authoritative remote input for `frame`. If it matches what was already
+    /// confirmed there, nothing else happens. Otherwise restores the state from just before
+    /// `frame` and re-steps every frame from there back to the present with the corrected input,
+    /// overwriting the saved states along the way - the actual "rollback".
+    pub fn receive_remote_input(
+        &mut self,
+        physics: &mut PhysicsWorld,
+        frame: u64,
+        input: InputBits,
+    ) {
+        self.last_remote_input = input;
+
+        let Some(index) = self.frames.iter().position(|f| f.frame == frame) else {
+            return; // Outside the rollback window - too late to reconcile, drop it.
+        };
+
+        if self.frames[index].remote_confirmed && self.frames[index].remote_input == input {
+            return; // Already confirmed with this exact value - nothing to redo.
+        }
+
+        if index == 0 {
+            // No saved state from just before this frame - same as "outside the window": there's
+            // no baseline to replay from, so drop the correction instead of restoring nothing and
+            // replaying on top of whatever the current state happens to be.
+            return;
+        }
+        physics.restore_state(&self.frames[index - 1].state);
+
+        let replay: Vec<(u64, InputBits, InputBits)> = self
+            .frames
+            .range(index..)
+            .map(|f| {
+                (
+                    f.frame,
+                    f.local_input,
+                    if f.frame == frame {
+                        input
+                    } else {
+                        f.remote_input
+                    },
+                )
+            })
+            .collect();
+
+        self.frames.truncate(index);
+        for (frame_number, local, remote) in replay {
+            let remote_confirmed = frame_number <= frame;
+            self.push_stepped_frame(physics, frame_number, local, remote, remote_confirmed);
+        }
+    }
+
+    /// Steps `physics` one frame with the given inputs and records the result, evicting the
+    /// oldest saved frame once the window is full.
+    fn push_stepped_frame(
+        &mut self,
+        physics: &mut PhysicsWorld,
+        frame: u64,
+        local: InputBits,
+        remote: InputBits,
+        remote_confirmed: bool,
+    ) {
+        let mut force = local.resolve_force();
+        force += remote.resolve_force();
+        let mut torque = local.resolve_torque();
+        torque += remote.resolve_torque();
+        physics.step_with_torque(FIXED_DT, force, torque);
+
+        self.frames.push_back(RollbackFrame {
+            frame,
+            state: physics.save_state(),
+            local_input: local,
+            remote_input: remote,
+            remote_confirmed,
+        });
+        if self.frames.len() > ROLLBACK_WINDOW {
+            self.frames.pop_front();
+        }
+    }
+}
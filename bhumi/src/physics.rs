@@ -1,11 +1,103 @@
+use nalgebra::{Unit, UnitQuaternion};
 use rapier3d::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Fixed simulation timestep in seconds. Every `step` call advances the sim by exactly this much
+/// simulated time regardless of the caller's real frame duration, so replaying an identical input
+/// sequence through `save_state`/`restore_state` always produces bit-identical results - the
+/// determinism a rollback netcode layer depends on.
+pub const FIXED_DT: f32 = 1.0 / 60.0;
+
+/// Everything about a `PhysicsWorld` that needs to round-trip through `save_state`/`restore_state`
+/// for rollback. `broad_phase`/`narrow_phase` are left out deliberately - they're acceleration
+/// structures `step` rebuilds fresh from `bodies`/`colliders` every call, not state of their own.
+#[derive(Serialize, Deserialize)]
+struct PhysicsSnapshot {
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    island_manager: IslandManager,
+    impulse_joints: ImpulseJointSet,
+    multibody_joints: MultibodyJointSet,
+    entities: HashMap<EntityId, EntityRecord>,
+    next_entity_id: EntityId,
+}
+
+/// Identifies a spawned entity (the drone, an obstacle, a pickup, a remote peer's drone)
+/// independently of the `RigidBodyHandle`/`ColliderHandle` backing it, so callers don't need to
+/// reach into Rapier's own handle types just to refer to "that pickup over there".
+pub type EntityId = u64;
+
+/// What kind of thing an `EntityRecord` is, so the renderer/gameplay code can tell them apart
+/// without guessing from shape or behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EntityKind {
+    Drone,
+    Obstacle,
+    Pickup,
+    Remote,
+}
+
+/// One entry in `PhysicsWorld`'s entity registry: the handles backing a spawned thing, plus what
+/// kind it is.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityRecord {
+    pub body: RigidBodyHandle,
+    pub collider: Option<ColliderHandle>,
+    pub kind: EntityKind,
+}
+
+/// `EntityId` of the drone itself, registered in the entity table by `PhysicsWorld::new` so
+/// callers that already know about `drone_handle` can keep using it, while newer code goes
+/// through `spawn`/`despawn`/`get_position`/`entities_by_kind` uniformly for every entity.
+pub const DRONE_ENTITY: EntityId = 0;
+
+/// Half the room's side length in meters - the drone flies inside a
+/// `ROOM_HALF_EXTENT * 2` cube bounded by six static walls.
+const ROOM_HALF_EXTENT: f32 = 10.0;
+/// Thickness of each wall/floor/ceiling collider.
+const WALL_THICKNESS: f32 = 0.5;
+
+/// How many steps a tunneling recovery push lasts once triggered.
+const TUNNEL_RECOVERY_FRAMES: u32 = 15;
+/// Magnitude of the corrective force applied each step while recovering from a tunneling event.
+const TUNNEL_RECOVERY_FORCE: f32 = 8.0;
+
+/// How strongly `stabilize`'s lean bank responds to lateral speed, in radians per m/s.
+const LEAN_SPEED_GAIN: f32 = 0.05;
+/// How strongly `stabilize`'s lean bank responds to yaw rate, in radians per rad/s.
+const LEAN_YAW_GAIN: f32 = 0.3;
+
+/// Active software recovery from a wall tunneling event that CCD still missed - a corrective
+/// push back along the hit surface's normal, applied for a few steps so the body is pushed
+/// cleanly out rather than left oscillating right at the boundary.
+struct Tunneling {
+    frames: u32,
+    dir: Vector<f32>,
+}
+
+/// Selectable flight regime. `set_flight_model` applies it to `gravity` and the drone's damping;
+/// `step` applies the per-step drag/lift force computation it implies.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FlightModel {
+    /// Zero gravity, relying on Rapier's linear damping alone to bleed off velocity - the
+    /// arcade-feeling default this sim has always used.
+    FreeFlight,
+    /// Constant downward gravity `g`, damping otherwise unchanged - weightier, but no drag/lift.
+    Gravity { g: f32 },
+    /// Gravity `g` plus a per-step quadratic drag force and a lift force proportional to forward
+    /// speed, replacing Rapier's linear damping (angular damping is unchanged) so thrust
+    /// interacts with actual aerodynamics.
+    Atmospheric { g: f32, drag: f32, lift: f32 },
+}
 
 /// Wrapper around Rapier physics world
-/// - Static room (floor + 4 walls + ceiling)  
+/// - Static room (floor + 4 walls + ceiling)
 /// - Dynamic drone body
 /// - Physics simulation in SI units (meters, seconds)
 pub struct PhysicsWorld {
     gravity: Vector<f32>,
+    flight_model: FlightModel,
     integration_parameters: IntegrationParameters,
     pipeline: PhysicsPipeline,
     island_manager: IslandManager,
@@ -16,8 +108,21 @@ pub struct PhysicsWorld {
     impulse_joints: ImpulseJointSet,
     multibody_joints: MultibodyJointSet,
     ccd_solver: CCDSolver,
+    query_pipeline: QueryPipeline,
     // handles
     drone_handle: RigidBodyHandle,
+    // tunneling recovery
+    prev_pos: Vector<f32>,
+    prev_vel: Vector<f32>,
+    tunneling: Option<Tunneling>,
+    // attitude stabilization, see `stabilize`
+    pub attitude_kp: f32,
+    pub attitude_kd: f32,
+    pub max_lean_angle: f32,
+    // entity registry - obstacles, pickups, remote drones, and (via `DRONE_ENTITY`) the drone
+    // itself, see `spawn`/`despawn`/`get_position`/`entities_by_kind`
+    entities: HashMap<EntityId, EntityRecord>,
+    next_entity_id: EntityId,
 }
 
 impl PhysicsWorld {
@@ -31,23 +136,55 @@ impl PhysicsWorld {
         let mut rb = RigidBodyBuilder::dynamic()
             .translation(vector![0.0, 0.0, -3.0]) // start 3m in front of cube
             .linvel(vector![0.0, 0.0, 0.0]) // no initial velocity - motion only via controls
+            .ccd_enabled(true) // the drone can move fast enough to tunnel through walls in one step
             .build();
         // set high damping for responsive control and easy stopping
         rb.set_linear_damping(0.9);  // Higher damping for quicker stops
         rb.set_angular_damping(0.9);
+        let prev_pos = *rb.translation();
+        let prev_vel = *rb.linvel();
         let drone_handle = bodies.insert(rb);
         let drone_collider = ColliderBuilder::ball(0.35)
             .restitution(0.3)
             .friction(0.7)
             .build();
-        colliders.insert_with_parent(drone_collider, drone_handle, &mut bodies);
+        let drone_collider_handle =
+            colliders.insert_with_parent(drone_collider, drone_handle, &mut bodies);
+
+        let mut entities = HashMap::new();
+        entities.insert(
+            DRONE_ENTITY,
+            EntityRecord {
+                body: drone_handle,
+                collider: Some(drone_collider_handle),
+                kind: EntityKind::Drone,
+            },
+        );
 
         // Simple cube made of wireframe (no solid colliders for now)
         // Cube size: 2x2x2 meters, centered at origin
 
+        // Solid room: floor, ceiling, and four walls bounding the flyable space, so the drone
+        // (and CCD) has something to actually collide with instead of flying through forever.
+        let e = ROOM_HALF_EXTENT;
+        let t = WALL_THICKNESS;
+        let wall = |hx: f32, hy: f32, hz: f32, translation: Vector<f32>| {
+            ColliderBuilder::cuboid(hx, hy, hz)
+                .translation(translation)
+                .friction(0.7)
+                .build()
+        };
+        colliders.insert(wall(e, t / 2.0, e, vector![0.0, -e, 0.0])); // floor
+        colliders.insert(wall(e, t / 2.0, e, vector![0.0, e, 0.0])); // ceiling
+        colliders.insert(wall(t / 2.0, e, e, vector![-e, 0.0, 0.0])); // -x wall
+        colliders.insert(wall(t / 2.0, e, e, vector![e, 0.0, 0.0])); // +x wall
+        colliders.insert(wall(e, e, t / 2.0, vector![0.0, 0.0, -e])); // -z wall
+        colliders.insert(wall(e, e, t / 2.0, vector![0.0, 0.0, e])); // +z wall
+
         Self {
             gravity,
-            integration_parameters: IntegrationParameters { dt: 1.0 / 60.0, ..Default::default() },
+            flight_model: FlightModel::FreeFlight,
+            integration_parameters: IntegrationParameters { dt: FIXED_DT, ..Default::default() },
             pipeline: PhysicsPipeline::new(),
             island_manager: IslandManager::new(),
             broad_phase: BroadPhaseBvh::new(),
@@ -57,19 +194,64 @@ impl PhysicsWorld {
             impulse_joints: ImpulseJointSet::new(),
             multibody_joints: MultibodyJointSet::new(),
             ccd_solver: CCDSolver::new(),
+            query_pipeline: QueryPipeline::new(),
             drone_handle,
+            prev_pos,
+            prev_vel,
+            tunneling: None,
+            entities,
+            next_entity_id: DRONE_ENTITY + 1,
+            attitude_kp: 4.0,
+            attitude_kd: 0.8,
+            max_lean_angle: std::f32::consts::FRAC_PI_4,
         }
     }
 
-    /// Step the physics world by `dt` seconds
+    /// Step the physics world forward by one `FIXED_DT` slice of simulated time.
     /// `force_world` is a Vector<f32> in world coords applied to the drone this step (e.g. thrust)
     /// Returns the drone position as [x,y,z]
-    pub fn step(&mut self, dt: f32, force_world: Vector<f32>) -> [f32; 3] {
-        // set the integration dt to the provided dt
-        self.integration_parameters.dt = dt.max(1.0 / 240.0); // clamp small dt
+    ///
+    /// Takes `_dt` for source compatibility with existing callers, but ignores it - the sim is
+    /// pinned to `FIXED_DT` so rollback replay is deterministic regardless of real frame rate.
+    /// A caller with a variable frame rate should call this once per `FIXED_DT` of elapsed time
+    /// (e.g. via an accumulator), not once per rendered frame.
+    pub fn step(&mut self, _dt: f32, force_world: Vector<f32>) -> [f32; 3] {
+        self.integration_parameters.dt = FIXED_DT;
+
+        // Snapshot where the drone is before the pipeline moves it this step, so the
+        // tunneling check below diffs against an actual pre-move position instead of
+        // `self.prev_pos`, which by this point already equals the current position.
+        let pre_step_pos = self
+            .bodies
+            .get(self.drone_handle)
+            .map(|rb| *rb.translation())
+            .unwrap_or(self.prev_pos);
+
         // apply force to drone
         if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
             rb.add_force(force_world, true);
+            if let Some(tunneling) = &mut self.tunneling {
+                rb.add_force(tunneling.dir * TUNNEL_RECOVERY_FORCE, true);
+                tunneling.frames -= 1;
+            }
+        }
+        if matches!(&self.tunneling, Some(t) if t.frames == 0) {
+            self.tunneling = None;
+        }
+
+        if let FlightModel::Atmospheric { drag, lift, .. } = self.flight_model {
+            if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
+                let velocity = *rb.linvel();
+                let speed = velocity.norm();
+                if speed > 0.0 {
+                    rb.add_force(-velocity * (drag * speed), true); // quadratic drag
+                }
+
+                let forward = rb.rotation() * vector![0.0, 0.0, 1.0];
+                let up = rb.rotation() * vector![0.0, 1.0, 0.0];
+                let forward_speed = velocity.dot(&forward);
+                rb.add_force(up * (lift * forward_speed), true);
+            }
         }
 
         // step the physics pipeline
@@ -87,14 +269,229 @@ impl PhysicsWorld {
             &(),
             &(),
         );
+        self.query_pipeline.update(&self.bodies, &self.colliders);
+
+        // CCD catches most tunneling, but at very high speed Rapier can still miss a thin wall
+        // in one step - this recovers from whatever CCD doesn't, by ray-casting the move the
+        // pipeline just made.
+        self.recover_from_tunneling(pre_step_pos);
 
         // read drone position
-        if let Some(rb) = self.bodies.get(self.drone_handle) {
-            let t = rb.translation();
-            [t.x, t.y, t.z]
+        let (pos, vel) = if let Some(rb) = self.bodies.get(self.drone_handle) {
+            (*rb.translation(), *rb.linvel())
         } else {
-            [0.0, 0.0, 0.0]
+            (self.prev_pos, self.prev_vel)
+        };
+        self.prev_pos = pos;
+        self.prev_vel = vel;
+        [pos.x, pos.y, pos.z]
+    }
+
+    /// Like `step`, but also applies a torque this frame - used by `Renderer::update`'s
+    /// pitch/yaw/roll `angular_force` accumulator alongside the plain linear `force_world` `step`
+    /// already takes.
+    pub fn step_with_torque(
+        &mut self,
+        dt: f32,
+        force_world: Vector<f32>,
+        torque_world: Vector<f32>,
+    ) -> [f32; 3] {
+        if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
+            rb.add_torque(torque_world, true);
         }
+        self.step(dt, force_world)
+    }
+
+    /// Casts a ray from `before` (where the drone was just before the pipeline stepped) to where
+    /// it is now; if that ray hits a wall before reaching the current position, the drone
+    /// tunneled through it this step. Snaps the drone back to just outside the hit point along
+    /// the wall's normal and starts a short corrective push so it's pushed cleanly out instead of
+    /// left embedded in (or oscillating right against) the collider.
+    fn recover_from_tunneling(&mut self, before: Vector<f32>) {
+        let Some(rb) = self.bodies.get(self.drone_handle) else {
+            return;
+        };
+        let current = *rb.translation();
+        let segment = current - before;
+        let distance = segment.norm();
+        if distance < 1e-6 {
+            return;
+        }
+        let direction = segment / distance;
+        let ray = Ray::new(before.into(), direction);
+        let filter = QueryFilter::default().exclude_rigid_body(self.drone_handle);
+
+        let Some((_, hit)) = self.query_pipeline.cast_ray_and_get_normal(
+            &self.bodies,
+            &self.colliders,
+            &ray,
+            distance,
+            true,
+            filter,
+        ) else {
+            return;
+        };
+        if hit.time_of_impact >= distance {
+            return; // reached the end of the segment without hitting anything - no tunneling.
+        }
+
+        const DRONE_RADIUS: f32 = 0.35;
+        const SKIN: f32 = 0.05;
+        let hit_point = ray.point_at(hit.time_of_impact);
+        let corrected = hit_point + hit.normal * (DRONE_RADIUS + SKIN);
+
+        if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
+            rb.set_translation(corrected.coords, true);
+            rb.set_linvel(Vector::new(0.0, 0.0, 0.0), true);
+        }
+        self.tunneling = Some(Tunneling {
+            frames: TUNNEL_RECOVERY_FRAMES,
+            dir: hit.normal,
+        });
+    }
+
+    /// Applies a proportional-derivative corrective torque that rights the drone toward
+    /// `desired_up` and banks it into whatever lateral motion/yaw it currently has, mirroring the
+    /// "lean into the turn" feel of the reference sim instead of letting rotation accumulate
+    /// unchecked. Call once per step alongside thrust/steering input.
+    pub fn stabilize(&mut self, _dt: f32, desired_up: Vector<f32>) {
+        let Some(rb) = self.bodies.get(self.drone_handle) else {
+            return;
+        };
+        let orientation = *rb.rotation();
+        let angvel = *rb.angvel();
+        let velocity = *rb.linvel();
+
+        let Some(desired_up) = Unit::try_new(desired_up, 1.0e-6) else {
+            return;
+        };
+
+        // Bank the up-target toward the drone's lateral velocity and yaw rate, so a turn looks
+        // like a lean instead of a flat pivot.
+        let body_right = orientation * vector![1.0, 0.0, 0.0];
+        let lateral_speed = velocity.dot(&body_right);
+        let lean = (lateral_speed * LEAN_SPEED_GAIN + angvel.y * LEAN_YAW_GAIN)
+            .clamp(-self.max_lean_angle, self.max_lean_angle);
+        let body_forward = orientation * vector![0.0, 0.0, 1.0];
+        let Some(bank_axis) = Unit::try_new(body_forward, 1.0e-6) else {
+            return;
+        };
+        let bank = UnitQuaternion::from_axis_angle(&bank_axis, lean);
+
+        let align = UnitQuaternion::rotation_between(&vector![0.0, 1.0, 0.0], &desired_up)
+            .unwrap_or_else(UnitQuaternion::identity);
+        let desired = bank * align;
+
+        let error = desired * orientation.inverse();
+        let (axis, angle) = error.axis_angle().unwrap_or((Vector::y_axis(), 0.0));
+        let angle = angle.clamp(-std::f32::consts::PI, std::f32::consts::PI);
+        let error_vector = axis.into_inner() * angle;
+
+        let torque = error_vector * self.attitude_kp - angvel * self.attitude_kd;
+        if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
+            rb.add_torque(torque, true);
+        }
+    }
+
+    /// Serializes the full simulation state (bodies, colliders, islands, joints) to bytes, so a
+    /// rollback netcode layer can stash it and `restore_state` back to it later.
+    pub fn save_state(&self) -> Vec<u8> {
+        let snapshot = PhysicsSnapshot {
+            bodies: self.bodies.clone(),
+            colliders: self.colliders.clone(),
+            island_manager: self.island_manager.clone(),
+            impulse_joints: self.impulse_joints.clone(),
+            multibody_joints: self.multibody_joints.clone(),
+            entities: self.entities.clone(),
+            next_entity_id: self.next_entity_id,
+        };
+        bincode::serialize(&snapshot).expect("PhysicsSnapshot always serializes")
+    }
+
+    /// Restores simulation state previously captured by `save_state`. `broad_phase`/
+    /// `narrow_phase` are reset rather than restored, since `step` rebuilds them fresh from
+    /// `bodies`/`colliders` every call.
+    pub fn restore_state(&mut self, bytes: &[u8]) {
+        let snapshot: PhysicsSnapshot =
+            bincode::deserialize(bytes).expect("restore_state bytes must come from save_state");
+        self.bodies = snapshot.bodies;
+        self.colliders = snapshot.colliders;
+        self.island_manager = snapshot.island_manager;
+        self.impulse_joints = snapshot.impulse_joints;
+        self.multibody_joints = snapshot.multibody_joints;
+        self.entities = snapshot.entities;
+        self.next_entity_id = snapshot.next_entity_id;
+        self.broad_phase = BroadPhaseBvh::new();
+        self.narrow_phase = NarrowPhase::new();
+    }
+
+    /// Registers a new entity (an obstacle, a pickup, a remote peer's drone, ...) with its own
+    /// rigid body at `transform` and an optional collider, returning the `EntityId` callers use
+    /// to refer to it afterward. `Obstacle`/`Pickup` spawn as fixed bodies since courses don't
+    /// move them; `Drone`/`Remote` spawn dynamic so they can actually fly.
+    pub fn spawn(
+        &mut self,
+        kind: EntityKind,
+        transform: Isometry<f32>,
+        collider: Option<Collider>,
+    ) -> EntityId {
+        let builder = match kind {
+            EntityKind::Obstacle | EntityKind::Pickup => RigidBodyBuilder::fixed(),
+            EntityKind::Drone | EntityKind::Remote => RigidBodyBuilder::dynamic(),
+        };
+        let body = self.bodies.insert(builder.position(transform).build());
+        let collider_handle =
+            collider.map(|c| self.colliders.insert_with_parent(c, body, &mut self.bodies));
+
+        let id = self.next_entity_id;
+        self.next_entity_id += 1;
+        self.entities.insert(
+            id,
+            EntityRecord {
+                body,
+                collider: collider_handle,
+                kind,
+            },
+        );
+        id
+    }
+
+    /// Removes an entity and its rigid body/collider from the simulation. A no-op if `id` isn't
+    /// registered (e.g. already despawned).
+    pub fn despawn(&mut self, id: EntityId) {
+        let Some(record) = self.entities.remove(&id) else {
+            return;
+        };
+        self.bodies.remove(
+            record.body,
+            &mut self.island_manager,
+            &mut self.colliders,
+            &mut self.impulse_joints,
+            &mut self.multibody_joints,
+            true,
+        );
+    }
+
+    /// World-space position of an entity, or `None` if `id` isn't registered.
+    pub fn get_position(&self, id: EntityId) -> Option<[f32; 3]> {
+        let record = self.entities.get(&id)?;
+        let t = self.bodies.get(record.body)?.translation();
+        Some([t.x, t.y, t.z])
+    }
+
+    /// Iterates every registered entity of `kind` along with its current position, so e.g. the
+    /// renderer can draw every `Pickup` without knowing their ids ahead of time.
+    pub fn entities_by_kind(
+        &self,
+        kind: EntityKind,
+    ) -> impl Iterator<Item = (EntityId, [f32; 3])> + '_ {
+        self.entities.iter().filter_map(move |(&id, record)| {
+            if record.kind != kind {
+                return None;
+            }
+            let t = self.bodies.get(record.body)?.translation();
+            Some((id, [t.x, t.y, t.z]))
+        })
     }
 
     /// Get drone position without stepping physics
@@ -107,6 +504,36 @@ impl PhysicsWorld {
         }
     }
 
+    /// Get drone orientation, for callers (e.g. the chase camera) that need the drone's own
+    /// basis rather than just its position.
+    pub fn get_drone_rotation(&self) -> UnitQuaternion<f32> {
+        self.bodies
+            .get(self.drone_handle)
+            .map(|rb| *rb.rotation())
+            .unwrap_or_else(UnitQuaternion::identity)
+    }
+
+    /// Switches the active flight regime: updates `gravity` for `step`'s pipeline call, and
+    /// swaps the drone's damping between Rapier's blunt linear damping (`FreeFlight`/`Gravity`)
+    /// and none at all (`Atmospheric`, where `step`'s own quadratic drag force takes over).
+    pub fn set_flight_model(&mut self, model: FlightModel) {
+        self.gravity = match model {
+            FlightModel::FreeFlight => Vector::new(0.0, 0.0, 0.0),
+            FlightModel::Gravity { g } | FlightModel::Atmospheric { g, .. } => {
+                Vector::new(0.0, -g, 0.0)
+            }
+        };
+        if let Some(rb) = self.bodies.get_mut(self.drone_handle) {
+            let damping = if matches!(model, FlightModel::Atmospheric { .. }) {
+                0.0
+            } else {
+                0.9
+            };
+            rb.set_linear_damping(damping);
+        }
+        self.flight_model = model;
+    }
+
     /// Get drone velocity
     pub fn get_drone_velocity(&self) -> [f32; 3] {
         if let Some(rb) = self.bodies.get(self.drone_handle) {
@@ -1,3 +1,27 @@
+use crate::InputEvent;
+
+/// A frontend's window/terminal backend: owns however it gets a `PixelBuffer` on screen and
+/// whatever raw input it reads, resolved into the shared `InputEvent` vocabulary so the core
+/// `Renderer` doesn't need to know which backend is driving it. Implemented once per backend
+/// (terminal/viuer, minifb, DRM/KMS, ...) so each one's own main loop stays a thin
+/// `new`/`handle_input`/`render_frame`/`should_exit` shell around `Renderer`.
+pub trait PixelRenderer {
+    /// Creates the backend, opening whatever window/device/terminal mode it needs.
+    fn new() -> Self
+    where
+        Self: Sized;
+
+    /// Draws `buffer` out to the screen/terminal/device this backend owns.
+    fn render_frame(&mut self, buffer: &PixelBuffer) -> std::io::Result<()>;
+
+    /// Reads whatever input has arrived since the last call, resolved into `InputEvent`s.
+    fn handle_input(&mut self) -> Vec<InputEvent>;
+
+    /// Whether the backend has decided the app should quit (e.g. a closed window or an escape
+    /// key), checked once per frame by the caller's main loop.
+    fn should_exit(&self) -> bool;
+}
+
 /// Standard pixel buffer used by all renderers
 /// Resolution: 320×240 (4:3 aspect ratio)
 /// Format: RGBA8 (32-bit per pixel)
@@ -6,6 +30,9 @@ pub struct PixelBuffer {
     pub width: u32,
     pub height: u32,
     pub pixels: Vec<[u8; 4]>, // RGBA
+    /// Per-pixel depth, parallel to `pixels`. Nearer is smaller; `f32::INFINITY` means "nothing
+    /// drawn here yet".
+    pub depth: Vec<f32>,
 }
 
 impl PixelBuffer {
@@ -17,12 +44,19 @@ impl PixelBuffer {
             width: WIDTH,
             height: HEIGHT,
             pixels: vec![[0, 0, 0, 255]; (WIDTH * HEIGHT) as usize], // Black with full alpha
+            depth: vec![f32::INFINITY; (WIDTH * HEIGHT) as usize],
         }
     }
 
-    /// Clear buffer to specified color
+    /// Reset every depth value to `f32::INFINITY`, as if nothing had been drawn yet.
+    pub fn clear_depth(&mut self) {
+        self.depth.fill(f32::INFINITY);
+    }
+
+    /// Clear the color buffer to `color` and the depth buffer to `f32::INFINITY`.
     pub fn clear(&mut self, color: [u8; 4]) {
         self.pixels.fill(color);
+        self.clear_depth();
     }
 
     /// Set pixel at coordinates (x, y) to color
@@ -89,4 +123,206 @@ impl PixelBuffer {
             }
         }
     }
+
+    /// Draw a filled, depth-tested triangle. Each vertex is (screen_x, screen_y, depth_z).
+    /// Rasterized with the classic edge-function/barycentric approach: walk the triangle's
+    /// bounding box, reject pixels outside any edge, and only write the ones whose interpolated
+    /// depth is nearer than what's already in `self.depth`.
+    pub fn draw_triangle(
+        &mut self,
+        v0: (f32, f32, f32),
+        v1: (f32, f32, f32),
+        v2: (f32, f32, f32),
+        color: [u8; 4],
+    ) {
+        fn edge(a: (f32, f32, f32), b: (f32, f32, f32), p: (f32, f32)) -> f32 {
+            (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+        }
+
+        let area = edge(v0, v1, (v2.0, v2.1));
+        if area == 0.0 {
+            return; // Degenerate triangle.
+        }
+
+        let min_x = v0.0.min(v1.0).min(v2.0).floor().max(0.0) as u32;
+        let max_x = v0.0.max(v1.0).max(v2.0).ceil().min(self.width as f32 - 1.0) as u32;
+        let min_y = v0.1.min(v1.1).min(v2.1).floor().max(0.0) as u32;
+        let max_y = v0.1.max(v1.1).max(v2.1).ceil().min(self.height as f32 - 1.0) as u32;
+        if min_x > max_x || min_y > max_y {
+            return; // Bounding box is entirely off-buffer.
+        }
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = (x as f32 + 0.5, y as f32 + 0.5);
+                let w0 = edge(v1, v2, p) / area;
+                let w1 = edge(v2, v0, p) / area;
+                let w2 = edge(v0, v1, p) / area;
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue; // Outside the triangle.
+                }
+
+                let z = w0 * v0.2 + w1 * v1.2 + w2 * v2.2;
+                let index = (y * self.width + x) as usize;
+                if z < self.depth[index] {
+                    self.depth[index] = z;
+                    self.pixels[index] = color;
+                }
+            }
+        }
+    }
+
+    /// Same rasterization as `draw_triangle`, but blends `wire_color` in near the triangle's
+    /// edges instead of leaving a flat `face_color` fill. `wire_width` is in the same units as
+    /// the barycentric coordinates (0.0..1.0 across the triangle), so a small value like `0.04`
+    /// gives a thin, anti-aliased edge rather than `draw_line`'s aliased one-pixel Bresenham.
+    pub fn draw_triangle_wire(
+        &mut self,
+        v0: (f32, f32, f32),
+        v1: (f32, f32, f32),
+        v2: (f32, f32, f32),
+        face_color: [u8; 4],
+        wire_color: [u8; 4],
+        wire_width: f32,
+    ) {
+        fn edge(a: (f32, f32, f32), b: (f32, f32, f32), p: (f32, f32)) -> f32 {
+            (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+        }
+
+        fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+            let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+            t * t * (3.0 - 2.0 * t)
+        }
+
+        fn blend(a: [u8; 4], b: [u8; 4], t: f32) -> [u8; 4] {
+            let lerp = |x: u8, y: u8| (x as f32 + (y as f32 - x as f32) * t).round() as u8;
+            [lerp(a[0], b[0]), lerp(a[1], b[1]), lerp(a[2], b[2]), lerp(a[3], b[3])]
+        }
+
+        let area = edge(v0, v1, (v2.0, v2.1));
+        if area == 0.0 {
+            return; // Degenerate triangle.
+        }
+
+        let min_x = v0.0.min(v1.0).min(v2.0).floor().max(0.0) as u32;
+        let max_x = v0.0.max(v1.0).max(v2.0).ceil().min(self.width as f32 - 1.0) as u32;
+        let min_y = v0.1.min(v1.1).min(v2.1).floor().max(0.0) as u32;
+        let max_y = v0.1.max(v1.1).max(v2.1).ceil().min(self.height as f32 - 1.0) as u32;
+        if min_x > max_x || min_y > max_y {
+            return; // Bounding box is entirely off-buffer.
+        }
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let p = (x as f32 + 0.5, y as f32 + 0.5);
+                let w0 = edge(v1, v2, p) / area;
+                let w1 = edge(v2, v0, p) / area;
+                let w2 = edge(v0, v1, p) / area;
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0 {
+                    continue; // Outside the triangle.
+                }
+
+                let z = w0 * v0.2 + w1 * v1.2 + w2 * v2.2;
+                let index = (y * self.width + x) as usize;
+                if z < self.depth[index] {
+                    self.depth[index] = z;
+                    let min_bary = w0.min(w1).min(w2);
+                    let edge_factor = 1.0 - smoothstep(0.0, wire_width, min_bary);
+                    self.pixels[index] = blend(face_color, wire_color, edge_factor);
+                }
+            }
+        }
+    }
+
+    /// Looks up the 3x5 bitmap for a single HUD-font glyph. Rows are top-to-bottom, and within a
+    /// row bit 2 (`0b100`) is the glyph's leftmost column. Covers only the characters the HUD
+    /// actually draws (digits, a handful of label letters, and `:`/`.`/`-`/space); unknown
+    /// characters are simply skipped by `draw_text`.
+    fn glyph(ch: char) -> Option<[u8; 5]> {
+        Some(match ch {
+            '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+            '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+            '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+            '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+            '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+            '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+            '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+            '7' => [0b111, 0b001, 0b010, 0b010, 0b010],
+            '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+            '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+            'F' => [0b111, 0b100, 0b111, 0b100, 0b100],
+            'P' => [0b111, 0b101, 0b111, 0b100, 0b100],
+            'S' => [0b111, 0b100, 0b111, 0b001, 0b111],
+            'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+            'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+            'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+            'R' => [0b111, 0b101, 0b110, 0b101, 0b101],
+            ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+            '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+            '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+            ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+            _ => return None,
+        })
+    }
+
+    /// Draws `text` with the built-in 3x5 bitmap font, one glyph cell (3px wide, 1px gap) per
+    /// character, top-left anchored at (x, y). Unsupported characters are skipped rather than
+    /// drawn as a placeholder box, since the HUD only ever feeds it its own known label text.
+    pub fn draw_text(&mut self, x: u32, y: u32, text: &str, color: [u8; 4]) {
+        const GLYPH_WIDTH: u32 = 3;
+        const GLYPH_HEIGHT: u32 = 5;
+        const ADVANCE: u32 = GLYPH_WIDTH + 1;
+
+        for (i, ch) in text.chars().enumerate() {
+            let Some(rows) = Self::glyph(ch) else { continue };
+            let cell_x = x + i as u32 * ADVANCE;
+            for (row, bits) in rows.iter().enumerate().take(GLYPH_HEIGHT as usize) {
+                for col in 0..GLYPH_WIDTH {
+                    if bits & (1 << (GLYPH_WIDTH - 1 - col)) != 0 {
+                        self.set_pixel(cell_x + col, y + row as u32, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draws an arc gauge centered at `(cx, cy)`: sweeps angle from `start_angle` toward
+    /// `end_angle` (radians) in proportion to `value / max`, plotting filled pixels along radii
+    /// from `inner_radius` to `outer_radius` at each swept angle. `value`/`max` outside 0.0..=1.0
+    /// once divided are clamped, so an over-range reading just pins the gauge at full rather than
+    /// sweeping past `end_angle`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_radial_bar(
+        &mut self,
+        cx: f32,
+        cy: f32,
+        inner_radius: f32,
+        outer_radius: f32,
+        start_angle: f32,
+        end_angle: f32,
+        value: f32,
+        max: f32,
+        color: [u8; 4],
+    ) {
+        const ANGLE_STEP: f32 = 0.02;
+        const RADIUS_STEP: f32 = 1.0;
+
+        let fraction = if max > 0.0 { (value / max).clamp(0.0, 1.0) } else { 0.0 };
+        let sweep_end = start_angle + (end_angle - start_angle) * fraction;
+
+        let mut angle = start_angle;
+        while angle <= sweep_end {
+            let (sin, cos) = angle.sin_cos();
+            let mut radius = inner_radius;
+            while radius <= outer_radius {
+                let x = cx + radius * cos;
+                let y = cy + radius * sin;
+                if x >= 0.0 && y >= 0.0 {
+                    self.set_pixel(x as u32, y as u32, color);
+                }
+                radius += RADIUS_STEP;
+            }
+            angle += ANGLE_STEP;
+        }
+    }
 }
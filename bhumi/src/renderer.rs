@@ -1,7 +1,40 @@
-use crate::{Camera, CameraMode, InputEvent, PhysicsWorld, PixelBuffer, world_to_screen};
-use nalgebra::Point3;
+use crate::{
+    clip_segment_to_screen_in_rect, world_to_screen_in_rect, Camera, CameraMode, CameraSet,
+    InputEvent, PhysicsWorld, PixelBuffer, Rect,
+};
+use nalgebra::{Point3, Vector3};
 use rapier3d::prelude::Vector;
 
+/// Lets a frontend decide which sub-rectangles of the output buffer to render into and which
+/// camera to use for each, instead of `Renderer::render`'s single full-frame view. Implement this
+/// to drive split-screen (e.g. two half-height viewports for chase + top-down) or
+/// picture-in-picture (a small minimap rectangle over the main view); `SingleViewport` is the
+/// default, full-frame behavior every backend had before this existed.
+pub trait RenderCallbacks {
+    /// Viewports to render this frame, each paired with the camera to render it from. Called
+    /// once per `render_with_callbacks`, before any drawing happens.
+    fn get_viewports(&mut self, renderer: &Renderer) -> Vec<(Rect, Camera)>;
+
+    /// Called once after every viewport above has been drawn into the shared buffer. The default
+    /// does nothing, since `render_frame`/`put_image_data` blit the buffer on their own schedule
+    /// regardless.
+    fn present(&mut self) {}
+}
+
+/// `RenderCallbacks` that renders one full-frame viewport using the renderer's own
+/// `active_camera` - the single-viewport behavior every backend had before multi-viewport
+/// rendering existed.
+pub struct SingleViewport;
+
+impl RenderCallbacks for SingleViewport {
+    fn get_viewports(&mut self, renderer: &Renderer) -> Vec<(Rect, Camera)> {
+        vec![(
+            Rect::full(renderer.buffer.width, renderer.buffer.height),
+            renderer.active_camera().clone(),
+        )]
+    }
+}
+
 /// Core 3D renderer that manages the world, camera, and pixel buffer
 pub struct Renderer {
     pub physics: PhysicsWorld,
@@ -9,6 +42,28 @@ pub struct Renderer {
     pub buffer: PixelBuffer,
     thrust_force: Vector<f32>,
     angular_force: Vector<f32>, // For pitch/yaw/roll
+    /// Vertical component of `thrust_force` as of the last step, kept around after the
+    /// per-frame reset so `render_hud` (which runs after `update`) still has something to
+    /// show on the thrust gauge.
+    last_vertical_thrust: f32,
+    /// A loaded scene script, if any. When present, `render` draws whatever it asks for each
+    /// frame instead of the hardcoded room/drone scene.
+    scene_script: Option<crate::scene_script::SceneScript>,
+    /// Whether `render` draws the procedural starfield background. On by default; toggleable
+    /// since it's an optional pass layered under the scene, not load-bearing for it.
+    starfield_enabled: bool,
+    /// Named scene cameras a caller can register and cycle through, on top of the
+    /// user-controlled `camera` above.
+    camera_set: CameraSet,
+    /// Whether `render_hud` draws anything this frame - a scene script's `config()` hook can
+    /// turn it off (e.g. for a cinematic fly-through with no overlay).
+    hud_enabled: bool,
+    /// Replaces `render_hud`'s own FPS line (the gauges and their labels still draw) when a scene script's `event()` hook asks for
+    /// it via a `hud_text` action, e.g. a racing HUD showing lap time instead.
+    hud_text_override: Option<String>,
+    /// Seconds since the current scene script was loaded, fed to its `event()` hook as
+    /// `SceneState::elapsed`. Unused with no script loaded.
+    scene_elapsed: f32,
 }
 
 impl Renderer {
@@ -20,7 +75,86 @@ impl Renderer {
             buffer: PixelBuffer::new(),
             thrust_force: Vector::new(0.0, 0.0, 0.0),
             angular_force: Vector::new(0.0, 0.0, 0.0),
+            last_vertical_thrust: 0.0,
+            scene_script: None,
+            starfield_enabled: true,
+            camera_set: CameraSet::new(),
+            hud_enabled: true,
+            hud_text_override: None,
+            scene_elapsed: 0.0,
+        }
+    }
+
+    /// Toggles the starfield background pass on/off.
+    pub fn toggle_starfield(&mut self) {
+        self.starfield_enabled = !self.starfield_enabled;
+    }
+
+    /// Registers a fixed scene camera by name, e.g. a chase, top-down, or ground vantage point,
+    /// so it can later be cycled into view by `cycle_camera`.
+    pub fn register_camera(
+        &mut self,
+        name: &str,
+        position: [f32; 3],
+        target: [f32; 3],
+        fov_degrees: f32,
+    ) {
+        self.camera_set.register(
+            name,
+            Point3::new(position[0], position[1], position[2]),
+            Point3::new(target[0], target[1], target[2]),
+            fov_degrees.to_radians(),
+        );
+    }
+
+    /// Cycles to the next registered scene camera, wrapping back to the user-controlled camera
+    /// after the last one.
+    pub fn cycle_camera(&mut self) {
+        self.camera_set.cycle();
+    }
+
+    /// Name of the currently active camera - a registered scene camera's name, or `"User"` when
+    /// the user-controlled camera is active.
+    pub fn active_camera_name(&self) -> &str {
+        self.camera_set.active_name()
+    }
+
+    /// The view-projection matrix to render with this frame: the active scene camera's if one is
+    /// cycled in, else the user-controlled `camera`'s.
+    fn active_view_projection(&self) -> nalgebra::Matrix4<f32> {
+        self.active_camera().get_view_projection_matrix()
+    }
+
+    /// The camera to render with this frame absent a `RenderCallbacks`: the active scene camera
+    /// if one is cycled in, else the user-controlled `camera`.
+    fn active_camera(&self) -> &Camera {
+        self.camera_set.active().unwrap_or(&self.camera)
+    }
+
+    /// Compiles and loads a scene script, making `render` draw from it instead of the hardcoded
+    /// scene from then on and applying its `config()` hook's startup policy (HUD/starfield
+    /// visibility, starting camera mode). Replaces any previously loaded script.
+    pub fn load_scene_script(&mut self, path: &str) -> Result<(), String> {
+        let script =
+            crate::scene_script::SceneScript::compile(path).map_err(|err| err.to_string())?;
+
+        let config = script.config().clone();
+        self.hud_enabled = config.show_hud;
+        self.starfield_enabled = config.starfield_enabled;
+        if let Some(mode) = config.starting_camera_mode {
+            self.camera.set_mode(mode);
         }
+
+        self.scene_script = Some(script);
+        self.scene_elapsed = 0.0;
+        self.hud_text_override = None;
+        Ok(())
+    }
+
+    /// Drops the loaded scene script, if any, reverting `render` to the hardcoded scene.
+    pub fn unload_scene_script(&mut self) {
+        self.scene_script = None;
+        self.hud_text_override = None;
     }
 
     /// Update simulation by one time step
@@ -29,159 +163,556 @@ impl Renderer {
         for event in input_events {
             match event {
                 // Translation forces (WASD cluster)
-                InputEvent::ThrustForward => self.thrust_force.z += 0.3,   // W - surge forward
-                InputEvent::ThrustBackward => self.thrust_force.z -= 0.3,  // S - surge backward  
-                InputEvent::ThrustLeft => self.thrust_force.x -= 0.3,      // A - sway left
-                InputEvent::ThrustRight => self.thrust_force.x += 0.3,     // D - sway right
-                InputEvent::ThrustUp => self.thrust_force.y += 0.5,        // SPACE - heave up
-                InputEvent::ThrustDown => self.thrust_force.y -= 0.5,      // C - heave down
-                
+                InputEvent::ThrustForward => self.thrust_force.z += 0.3, // W - surge forward
+                InputEvent::ThrustBackward => self.thrust_force.z -= 0.3, // S - surge backward
+                InputEvent::ThrustLeft => self.thrust_force.x -= 0.3,    // A - sway left
+                InputEvent::ThrustRight => self.thrust_force.x += 0.3,   // D - sway right
+                InputEvent::ThrustUp => self.thrust_force.y += 0.5,      // SPACE - heave up
+                InputEvent::ThrustDown => self.thrust_force.y -= 0.5,    // C - heave down
+
                 // Rotational torques (IJKL cluster) - very gentle forces for subtle rotation
-                InputEvent::PitchUp => self.angular_force.x -= 0.05,       // I - pitch nose up
-                InputEvent::PitchDown => self.angular_force.x += 0.05,     // K - pitch nose down
-                InputEvent::YawLeft => self.angular_force.y -= 0.05,       // J - yaw turn left
-                InputEvent::YawRight => self.angular_force.y += 0.05,      // L - yaw turn right
-                InputEvent::RollLeft => self.angular_force.z -= 0.05,      // U - roll bank left
-                InputEvent::RollRight => self.angular_force.z += 0.05,     // O - roll bank right
-                
+                InputEvent::PitchUp => self.angular_force.x -= 0.05, // I - pitch nose up
+                InputEvent::PitchDown => self.angular_force.x += 0.05, // K - pitch nose down
+                InputEvent::YawLeft => self.angular_force.y -= 0.05, // J - yaw turn left
+                InputEvent::YawRight => self.angular_force.y += 0.05, // L - yaw turn right
+                InputEvent::RollLeft => self.angular_force.z -= 0.05, // U - roll bank left
+                InputEvent::RollRight => self.angular_force.z += 0.05, // O - roll bank right
+
+                // Analog translation/rotation, e.g. from a game controller stick - magnitude is
+                // already deadzone-normalized by the input source, so we just accumulate it.
+                InputEvent::Thrust { x, y, z } => {
+                    self.thrust_force.x += x;
+                    self.thrust_force.y += y;
+                    self.thrust_force.z += z;
+                }
+                InputEvent::Torque { pitch, yaw, roll } => {
+                    self.angular_force.x += pitch;
+                    self.angular_force.y += yaw;
+                    self.angular_force.z += roll;
+                }
+
+                // Relative mouse motion for the flycam's / free-look's look direction - a no-op
+                // in `ThirdPerson`, since only `Flycam`/`FreeFly`/`FirstPerson` read the state
+                // these feed.
+                InputEvent::MouseLook { dx, dy } => {
+                    self.camera.flycam_look(*dx, *dy);
+                    self.camera.free_look_look(*dx, *dy);
+                }
+
                 // Utility
                 InputEvent::CameraMode(mode) => self.camera.set_mode(*mode),
                 InputEvent::Reset => self.physics.reset_drone(),
-                InputEvent::Stop => self.physics.stop_drone(),
+                // `GentleStop`/`EmergencyBrake` both resolve to the same zero-everything stop as
+                // plain `Stop` today - the distinction is for a frontend's own input handling
+                // (e.g. telling a tap from a held modifier apart in its own logs/UI).
+                InputEvent::Stop | InputEvent::GentleStop | InputEvent::EmergencyBrake => {
+                    self.physics.stop_drone()
+                }
+
+                // Steering is the same drone-facing rotation as the bare Pitch/Yaw cluster above;
+                // Look* instead only re-orients the free-look cameras via the same path
+                // `MouseLook` uses, so it's a no-op on the drone's own angular_force.
+                InputEvent::SteerPitchUp => self.angular_force.x -= 0.05,
+                InputEvent::SteerPitchDown => self.angular_force.x += 0.05,
+                InputEvent::SteerYawLeft => self.angular_force.y -= 0.05,
+                InputEvent::SteerYawRight => self.angular_force.y += 0.05,
+                InputEvent::SteerRollLeft => self.angular_force.z -= 0.05,
+                InputEvent::SteerRollRight => self.angular_force.z += 0.05,
+                InputEvent::LookPitchUp => {
+                    self.camera.flycam_look(0.0, -1.0);
+                    self.camera.free_look_look(0.0, -1.0);
+                }
+                InputEvent::LookPitchDown => {
+                    self.camera.flycam_look(0.0, 1.0);
+                    self.camera.free_look_look(0.0, 1.0);
+                }
+                InputEvent::LookYawLeft => {
+                    self.camera.flycam_look(-1.0, 0.0);
+                    self.camera.free_look_look(-1.0, 0.0);
+                }
+                InputEvent::LookYawRight => {
+                    self.camera.flycam_look(1.0, 0.0);
+                    self.camera.free_look_look(1.0, 0.0);
+                }
+                InputEvent::LookRollLeft | InputEvent::LookRollRight => {} // No roll axis in look mode.
+
                 InputEvent::Exit => {} // Handled by renderer implementation
             }
         }
 
-        // Step physics simulation with both linear and angular forces
-        let drone_pos = self.physics.step_with_torque(dt, self.thrust_force, self.angular_force);
+        self.step_and_update_camera(dt);
+    }
+
+    /// Steps physics with the currently accumulated `thrust_force`/`angular_force`, updates the
+    /// camera from the result, and resets both accumulators for the next frame. Shared by the
+    /// `InputEvent`-based `update` and the `ActionHandler`-based `update_from_actions`, so the two
+    /// input paths can't drift apart on how a frame's forces actually get applied.
+    fn step_and_update_camera(&mut self, dt: f32) {
+        // Step physics simulation with both linear and angular forces - this runs regardless of
+        // camera mode, since Flycam detaches the *view* from the drone, not the drone's own
+        // flight.
+        let drone_pos = self
+            .physics
+            .step_with_torque(dt, self.thrust_force, self.angular_force);
+
+        if matches!(self.camera.mode, CameraMode::Flycam) {
+            // Reuse the same movement-key accumulator as the flycam's thrust direction, rather
+            // than introducing a second set of WASD bindings just for this mode.
+            let thrust_dir = Vector3::new(
+                self.thrust_force.x,
+                self.thrust_force.y,
+                self.thrust_force.z,
+            );
+            self.camera.flycam_update(thrust_dir, dt);
+        } else if matches!(
+            self.camera.mode,
+            CameraMode::FreeFly | CameraMode::FirstPerson
+        ) {
+            let move_dir = Vector3::new(
+                self.thrust_force.x,
+                self.thrust_force.y,
+                self.thrust_force.z,
+            );
+            let anchor = matches!(self.camera.mode, CameraMode::FirstPerson)
+                .then(|| Point3::new(drone_pos[0], drone_pos[1], drone_pos[2]));
+            self.camera.free_look_move(move_dir, dt, anchor);
+        } else {
+            // Update camera based on drone position and orientation
+            let drone_rotation = self.physics.get_drone_rotation();
+            self.camera.update(drone_pos, drone_rotation, dt);
+        }
 
-        // Reset forces (apply only for this frame)
+        // Reset forces (apply only for this frame), remembering the vertical component for
+        // `render_hud`'s thrust gauge.
+        self.last_vertical_thrust = self.thrust_force.y;
         self.thrust_force = Vector::new(0.0, 0.0, 0.0);
         self.angular_force = Vector::new(0.0, 0.0, 0.0);
 
-        // Update camera based on drone position and orientation
-        let drone_rotation = self.physics.get_drone_rotation();
-        self.camera.update(drone_pos, drone_rotation);
+        self.scene_elapsed += dt;
+        if let Some(action) = self.run_scene_event(drone_pos) {
+            self.apply_scene_action(action);
+        }
+    }
+
+    /// Calls the loaded scene script's `event()` hook, if any, with this frame's drone state.
+    fn run_scene_event(&mut self, drone_pos: [f32; 3]) -> Option<crate::scene_script::SceneAction> {
+        let velocity = self.physics.get_drone_velocity();
+        let elapsed = self.scene_elapsed;
+        let script = self.scene_script.as_mut()?;
+        script.run_event(crate::scene_script::SceneState {
+            position: drone_pos,
+            velocity,
+            elapsed,
+        })
+    }
+
+    /// Applies a single action a scene script's `event()` hook asked for this frame. `GentleStop`
+    /// maps onto the same `stop_drone` the plain `InputEvent::Stop` uses - the renderer has only
+    /// one notion of "stop the drone", scripts just get to request it from their own logic too.
+    fn apply_scene_action(&mut self, action: crate::scene_script::SceneAction) {
+        use crate::scene_script::SceneAction;
+
+        match action {
+            SceneAction::SetCameraMode(mode) => self.camera.set_mode(mode),
+            SceneAction::SetFovDegrees(degrees) => self.camera.fov = degrees.to_radians(),
+            SceneAction::Reset => self.physics.reset_drone(),
+            SceneAction::GentleStop => self.physics.stop_drone(),
+            SceneAction::HudText(text) => self.hud_text_override = Some(text),
+        }
+    }
+
+    /// Update simulation by one time step, resolving forces from an `ActionHandler`'s remappable
+    /// bindings instead of a pre-resolved `InputEvent` list. Additive alongside `update` - existing
+    /// frontends keep calling `update` unchanged; a frontend can opt into this path instead once it
+    /// wants remappable controls.
+    pub fn update_from_actions(
+        &mut self,
+        dt: f32,
+        action_handler: &crate::actions::ActionHandler,
+        keys: &crate::input::Keys,
+    ) {
+        use crate::actions::{AxisAction, ButtonAction};
+
+        self.thrust_force.z += action_handler.axis(keys, AxisAction::ThrustSurge) * 0.3;
+        self.thrust_force.x += action_handler.axis(keys, AxisAction::ThrustSway) * 0.3;
+        self.thrust_force.y += action_handler.axis(keys, AxisAction::Heave) * 0.5;
+        self.angular_force.x += action_handler.axis(keys, AxisAction::Pitch) * 0.05;
+        self.angular_force.y += action_handler.axis(keys, AxisAction::Yaw) * 0.05;
+        self.angular_force.z += action_handler.axis(keys, AxisAction::Roll) * 0.05;
+
+        if action_handler.button(keys, ButtonAction::Reset) {
+            self.physics.reset_drone();
+        }
+        if action_handler.button(keys, ButtonAction::Stop) {
+            self.physics.stop_drone();
+        }
+
+        self.step_and_update_camera(dt);
     }
 
-    /// Render current frame to pixel buffer
+    /// Render current frame to pixel buffer, using a single viewport covering the whole buffer
+    /// and the renderer's own `active_camera`. Equivalent to calling `render_with_callbacks` with
+    /// `SingleViewport` - kept as the zero-argument entry point every existing backend already
+    /// calls.
     pub fn render(&mut self) {
-        // Clear buffer to dark background for ASCII visibility
         self.buffer.clear([20, 20, 30, 255]); // Dark blue/black
 
-        // Get current matrices
-        let view_proj = self.camera.get_view_projection_matrix();
+        let rect = Rect::full(self.buffer.width, self.buffer.height);
+        let camera = self.active_camera().clone();
+        self.render_viewport(rect, &camera);
+    }
+
+    /// Render current frame across whichever viewports `callbacks` asks for, each with its own
+    /// camera - e.g. two half-height viewports for a chase/top-down split-screen, or a full-frame
+    /// view plus a small picture-in-picture minimap rectangle. `callbacks.present()` runs once
+    /// after every viewport has been drawn into the shared buffer.
+    pub fn render_with_callbacks(&mut self, callbacks: &mut dyn RenderCallbacks) {
+        self.buffer.clear([20, 20, 30, 255]);
+
+        let viewports = callbacks.get_viewports(self);
+        for (rect, camera) in &viewports {
+            self.render_viewport(*rect, camera);
+        }
+
+        callbacks.present();
+    }
+
+    /// Draws one viewport's worth of frame: starfield, then either the loaded scene script's
+    /// commands or the hardcoded room/drone scene, all projected with `camera` and clipped into
+    /// `rect`.
+    fn render_viewport(&mut self, rect: Rect, camera: &Camera) {
+        let view_proj = camera.get_view_projection_matrix();
+
+        if self.starfield_enabled {
+            self.render_starfield(camera, rect);
+        }
+
+        if self.scene_script.is_some() {
+            self.render_scripted_scene(&view_proj, rect);
+        } else {
+            self.render_room(&view_proj, rect);
+            self.render_drone(&view_proj, rect);
+        }
+    }
+
+    /// Draws whatever the loaded scene script asked for this frame. Falls back to the hardcoded
+    /// room/drone scene on a script error, so a bad scene script degrades gracefully instead of
+    /// leaving the buffer blank.
+    fn render_scripted_scene(&mut self, view_proj: &nalgebra::Matrix4<f32>, rect: Rect) {
+        let drone_pos = self.physics.get_drone_position();
+        let Some(script) = self.scene_script.as_mut() else {
+            return;
+        };
+
+        match script.run_frame(drone_pos) {
+            Some(commands) => {
+                for command in commands {
+                    match command {
+                        crate::scene_script::DrawCommand::Cube {
+                            x,
+                            y,
+                            z,
+                            size,
+                            color,
+                        } => {
+                            self.render_cube_at(
+                                view_proj,
+                                x,
+                                y,
+                                z,
+                                size,
+                                [color[0], color[1], color[2], 255],
+                                rect,
+                            );
+                        }
+                        crate::scene_script::DrawCommand::Line3 {
+                            x0,
+                            y0,
+                            z0,
+                            x1,
+                            y1,
+                            z1,
+                            color,
+                        } => {
+                            self.render_line3d(
+                                view_proj,
+                                (x0, y0, z0),
+                                (x1, y1, z1),
+                                [color[0], color[1], color[2], 255],
+                                rect,
+                            );
+                        }
+                    }
+                }
+            }
+            None => {
+                self.render_room(view_proj, rect);
+                self.render_drone(view_proj, rect);
+            }
+        }
+    }
+
+    /// Compositable HUD layer, drawn after the 3D pass rather than folded into it: live FPS
+    /// (from the frame's `dt`) plus radial gauges for current speed and vertical thrust. Neither
+    /// `update` nor `render` call this on their own - each back-end opts in by calling it. A
+    /// scene script can suppress it entirely via `config()`'s `show_hud`, or replace the FPS line
+    /// with its own text via `event()`'s `hud_text` action.
+    pub fn render_hud(&mut self, dt: f32) {
+        if !self.hud_enabled {
+            return;
+        }
+
+        match &self.hud_text_override {
+            Some(text) => self.buffer.draw_text(4, 4, text, [255, 255, 255, 255]),
+            None => {
+                let fps = if dt > 0.0 { 1.0 / dt } else { 0.0 };
+                self.buffer
+                    .draw_text(4, 4, &format!("FPS:{:.0}", fps), [255, 255, 255, 255]);
+            }
+        }
+
+        const SPEED_MAX: f32 = 10.0;
+        const THRUST_MAX: f32 = 0.5;
+        const GAUGE_START: f32 = -std::f32::consts::FRAC_PI_2;
+        const GAUGE_END: f32 = std::f32::consts::FRAC_PI_2;
+
+        let velocity = self.physics.get_drone_velocity();
+        let speed =
+            (velocity[0] * velocity[0] + velocity[1] * velocity[1] + velocity[2] * velocity[2])
+                .sqrt();
+
+        self.buffer.draw_text(4, 12, "SPD", [80, 200, 255, 255]);
+        self.buffer.draw_radial_bar(
+            50.0,
+            16.0,
+            6.0,
+            14.0,
+            GAUGE_START,
+            GAUGE_END,
+            speed,
+            SPEED_MAX,
+            [80, 200, 255, 255],
+        );
 
-        // Remove static crosshair to see actual 3D content
+        self.buffer.draw_text(4, 20, "THR", [255, 160, 80, 255]);
+        self.buffer.draw_radial_bar(
+            50.0,
+            24.0,
+            6.0,
+            14.0,
+            GAUGE_START,
+            GAUGE_END,
+            self.last_vertical_thrust.abs(),
+            THRUST_MAX,
+            [255, 160, 80, 255],
+        );
+    }
+
+    /// Deterministic, direction-hashed starfield background: for every pixel in `rect`, computes
+    /// the view ray's world direction from `camera`'s basis and field of view, then hashes that
+    /// direction into a pseudo-random brightness to decide whether a star sits there. Hashing the
+    /// direction (rather than the pixel) means turning the camera pans across the same stars
+    /// instead of washing the sky out to noise, since the depth buffer is still infinity
+    /// everywhere at this point, real geometry drawn afterward paints over it normally.
+    fn render_starfield(&mut self, camera: &Camera, rect: Rect) {
+        let forward = (camera.target - camera.position).normalize();
+        let right = forward.cross(&camera.up).normalize();
+        let up = right.cross(&forward).normalize();
 
-        // Render 3D cube wireframe
-        self.render_room(&view_proj);
+        let tan_half_fov = (camera.fov * 0.5).tan();
 
-        // Always render drone (visible in third-person view)
-        self.render_drone(&view_proj);
+        for y in rect.y..(rect.y + rect.height) {
+            for x in rect.x..(rect.x + rect.width) {
+                let local_x = x - rect.x;
+                let local_y = y - rect.y;
+                let ndc_x = (2.0 * (local_x as f32 + 0.5) / rect.width as f32 - 1.0)
+                    * camera.aspect
+                    * tan_half_fov;
+                let ndc_y =
+                    (1.0 - 2.0 * (local_y as f32 + 0.5) / rect.height as f32) * tan_half_fov;
+                let dir = (forward + right * ndc_x + up * ndc_y).normalize();
+
+                if let Some(brightness) = star_brightness(dir) {
+                    let v = brightness;
+                    self.buffer.set_pixel(x, y, [v, v, v, 255]);
+                }
+            }
+        }
     }
 
     /// Render infinite grid of cubes
-    fn render_room(&mut self, view_proj: &nalgebra::Matrix4<f32>) {
+    fn render_room(&mut self, view_proj: &nalgebra::Matrix4<f32>, rect: Rect) {
         let cube_color = [255, 255, 255, 255]; // Bright white for visibility
-        
+
         // Get drone position to center the grid around
         let drone_pos = self.physics.get_drone_position();
         let drone_x = drone_pos[0];
-        let drone_y = drone_pos[1]; 
+        let drone_y = drone_pos[1];
         let drone_z = drone_pos[2];
-        
+
         // Ultra-sparse reference grid - nearest 4 cubes only in each direction
-        let cube_size = 2.0;      // 2x2x2 meter cubes 
-        let cube_spacing = 15.0;  // 15 meter spacing between cubes (even more spread out)
-        let grid_radius = 1;      // Only 3x3x3 total (27 cubes max)
-        
+        let cube_size = 2.0; // 2x2x2 meter cubes
+        let cube_spacing = 15.0; // 15 meter spacing between cubes (even more spread out)
+        let grid_radius = 1; // Only 3x3x3 total (27 cubes max)
+
         // Calculate which sparse grid cell the drone is in
         let grid_center_x = (drone_x / cube_spacing).round() as i32;
         let grid_center_y = (drone_y / cube_spacing).round() as i32;
         let grid_center_z = (drone_z / cube_spacing).round() as i32;
-        
+
         // Render sparse cube grid as reference markers
         for gx in (grid_center_x - grid_radius)..=(grid_center_x + grid_radius) {
             for gy in (grid_center_y - grid_radius)..=(grid_center_y + grid_radius) {
                 for gz in (grid_center_z - grid_radius)..=(grid_center_z + grid_radius) {
                     // World position of this reference cube (spaced 10m apart)
                     let cube_x = gx as f32 * cube_spacing;
-                    let cube_y = gy as f32 * cube_spacing;  
+                    let cube_y = gy as f32 * cube_spacing;
                     let cube_z = gz as f32 * cube_spacing;
-                    
-                    self.render_cube_at(view_proj, cube_x, cube_y, cube_z, cube_size, cube_color);
+
+                    self.render_cube_at(
+                        view_proj, cube_x, cube_y, cube_z, cube_size, cube_color, rect,
+                    );
                 }
             }
         }
     }
-    
-    /// Render a single cube at given world position
-    fn render_cube_at(&mut self, view_proj: &nalgebra::Matrix4<f32>, center_x: f32, center_y: f32, center_z: f32, size: f32, color: [u8; 4]) {
+
+    /// Render a single cube at given world position, clipped into `rect`.
+    #[allow(clippy::too_many_arguments)]
+    fn render_cube_at(
+        &mut self,
+        view_proj: &nalgebra::Matrix4<f32>,
+        center_x: f32,
+        center_y: f32,
+        center_z: f32,
+        size: f32,
+        color: [u8; 4],
+        rect: Rect,
+    ) {
         let half_size = size / 2.0;
-        
+
         // Cube corners relative to center
         let corners = [
             // Front face
-            Point3::new(center_x - half_size, center_y - half_size, center_z - half_size), // 0
-            Point3::new(center_x + half_size, center_y - half_size, center_z - half_size), // 1
-            Point3::new(center_x + half_size, center_y + half_size, center_z - half_size), // 2
-            Point3::new(center_x - half_size, center_y + half_size, center_z - half_size), // 3
+            Point3::new(
+                center_x - half_size,
+                center_y - half_size,
+                center_z - half_size,
+            ), // 0
+            Point3::new(
+                center_x + half_size,
+                center_y - half_size,
+                center_z - half_size,
+            ), // 1
+            Point3::new(
+                center_x + half_size,
+                center_y + half_size,
+                center_z - half_size,
+            ), // 2
+            Point3::new(
+                center_x - half_size,
+                center_y + half_size,
+                center_z - half_size,
+            ), // 3
             // Back face
-            Point3::new(center_x - half_size, center_y - half_size, center_z + half_size), // 4
-            Point3::new(center_x + half_size, center_y - half_size, center_z + half_size), // 5
-            Point3::new(center_x + half_size, center_y + half_size, center_z + half_size), // 6
-            Point3::new(center_x - half_size, center_y + half_size, center_z + half_size), // 7
+            Point3::new(
+                center_x - half_size,
+                center_y - half_size,
+                center_z + half_size,
+            ), // 4
+            Point3::new(
+                center_x + half_size,
+                center_y - half_size,
+                center_z + half_size,
+            ), // 5
+            Point3::new(
+                center_x + half_size,
+                center_y + half_size,
+                center_z + half_size,
+            ), // 6
+            Point3::new(
+                center_x - half_size,
+                center_y + half_size,
+                center_z + half_size,
+            ), // 7
         ];
 
-        // Convert corners to screen space
-        let mut screen_corners = Vec::new();
+        // Convert corners to (screen_x, screen_y, depth) triangle vertices; `None` means behind
+        // the camera, and any face touching one is skipped rather than drawn with a garbage
+        // vertex.
+        let mut screen_corners = Vec::with_capacity(corners.len());
         for corner in corners.iter() {
-            if let Some(screen_pos) = world_to_screen(*corner, view_proj, self.buffer.width, self.buffer.height) {
-                screen_corners.push(Some((screen_pos.0 as u32, screen_pos.1 as u32)));
-            } else {
-                screen_corners.push(None);
-            }
+            screen_corners.push(world_to_screen_in_rect(*corner, view_proj, rect));
         }
 
-        // Draw cube wireframe edges
-        let edges = [
-            // Front face
-            (0, 1, color), (1, 2, color), (2, 3, color), (3, 0, color),
-            // Back face  
-            (4, 5, color), (5, 6, color), (6, 7, color), (7, 4, color),
-            // Connecting edges (front to back)
-            (0, 4, color), (1, 5, color), (2, 6, color), (3, 7, color),
+        const WIRE_COLOR: [u8; 4] = [10, 10, 10, 255];
+        const WIRE_WIDTH: f32 = 0.04;
+
+        // Six faces, each a quad of corner indices wound consistently so the two triangles
+        // (a,b,c) and (a,c,d) cover it without a diagonal gap.
+        let faces: [[usize; 4]; 6] = [
+            [0, 1, 2, 3], // front
+            [4, 5, 6, 7], // back
+            [0, 3, 7, 4], // left
+            [1, 2, 6, 5], // right
+            [3, 2, 6, 7], // top
+            [0, 1, 5, 4], // bottom
         ];
 
-        for (start_idx, end_idx, edge_color) in edges.iter() {
-            if let (Some(start), Some(end)) = (screen_corners[*start_idx], screen_corners[*end_idx]) {
-                self.buffer.draw_line(start.0, start.1, end.0, end.1, *edge_color);
+        for face in faces.iter() {
+            if let (Some(a), Some(b), Some(c), Some(d)) = (
+                screen_corners[face[0]],
+                screen_corners[face[1]],
+                screen_corners[face[2]],
+                screen_corners[face[3]],
+            ) {
+                self.buffer
+                    .draw_triangle_wire(a, b, c, color, WIRE_COLOR, WIRE_WIDTH);
+                self.buffer
+                    .draw_triangle_wire(a, c, d, color, WIRE_COLOR, WIRE_WIDTH);
             }
         }
     }
 
-    /// Render the drone as a bright red dot/cross
-    fn render_drone(&mut self, view_proj: &nalgebra::Matrix4<f32>) {
+    /// Render a straight line between two world-space points, near-plane clipped via
+    /// `clip_segment_to_screen_in_rect` so a segment straddling the camera still draws its
+    /// visible half instead of popping out of existence entirely - good enough for the thin
+    /// debug/scene geometry a scene script draws with `draw_line3d` (no depth test against the 3D
+    /// scene).
+    fn render_line3d(
+        &mut self,
+        view_proj: &nalgebra::Matrix4<f32>,
+        from: (f32, f32, f32),
+        to: (f32, f32, f32),
+        color: [u8; 4],
+        rect: Rect,
+    ) {
+        let from_point = Point3::new(from.0, from.1, from.2);
+        let to_point = Point3::new(to.0, to.1, to.2);
+
+        if let Some((a, b)) = clip_segment_to_screen_in_rect(from_point, to_point, view_proj, rect)
+        {
+            self.buffer
+                .draw_line(a.0 as u32, a.1 as u32, b.0 as u32, b.1 as u32, color);
+        }
+    }
+
+    /// Render the drone as a bright red dot/cross, clipped into `rect`.
+    fn render_drone(&mut self, view_proj: &nalgebra::Matrix4<f32>, rect: Rect) {
         let drone_pos = self.physics.get_drone_position();
         let drone_point = Point3::new(drone_pos[0], drone_pos[1], drone_pos[2]);
 
-        if let Some(screen_pos) = world_to_screen(
-            drone_point,
-            view_proj,
-            self.buffer.width,
-            self.buffer.height,
-        ) {
+        if let Some(screen_pos) = world_to_screen_in_rect(drone_point, view_proj, rect) {
             let x = screen_pos.0 as u32;
             let y = screen_pos.1 as u32;
             let drone_color = [255, 0, 0, 255]; // Bright red
 
             // Draw drone as a larger cross for visibility
             let size = 8; // Bigger size
-            if x >= size
-                && y >= size
-                && x + size < self.buffer.width
-                && y + size < self.buffer.height
+            if x >= rect.x + size
+                && y >= rect.y + size
+                && x + size < rect.x + rect.width
+                && y + size < rect.y + rect.height
             {
                 // Draw cross
                 self.buffer.draw_line(x - size, y, x + size, y, drone_color);
@@ -204,3 +735,25 @@ impl Renderer {
         self.physics.get_drone_velocity()
     }
 }
+
+/// Hashes a (near-unit) direction vector into a deterministic pseudo-random value in 0.0..1.0.
+/// Pure function of direction, so the same point in the sky always hashes to the same value
+/// regardless of frame or viewer pose - the classic GLSL "sin-dot-fract" hash, just fed a 3D
+/// direction instead of a 2D screen coordinate.
+fn hash_direction(dir: Vector3<f32>) -> f32 {
+    let dot = dir.x * 12.9898 + dir.y * 78.233 + dir.z * 37.719;
+    (dot.sin() * 43758.5453).fract().abs()
+}
+
+/// Star density as a fraction of view directions that hash to a star.
+const STAR_DENSITY: f32 = 0.0015;
+
+/// Returns a star's greyscale brightness (0..255) if `dir` hashes to one, else `None`.
+fn star_brightness(dir: Vector3<f32>) -> Option<u8> {
+    let hash = hash_direction(dir);
+    if hash < STAR_DENSITY {
+        Some((128.0 + (hash / STAR_DENSITY) * 127.0) as u8)
+    } else {
+        None
+    }
+}
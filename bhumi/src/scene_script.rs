@@ -0,0 +1,274 @@
+//! Per-frame scriptable scene: a `rhai` script's `scene()` function decides what `Renderer::render`
+//! draws by calling back into a small `draw_cube`/`draw_line3d`/`drone_pos` API, instead of
+//! `render_room`'s grid spacing/radius/colors being baked into Rust. Mirrors how
+//! `bhumi-terminal`'s autopilot embeds `rhai` for gameplay logic, just driving drawing instead of
+//! thrust.
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// One primitive the script asked to have drawn this frame, collected by its `draw_cube`/
+/// `draw_line3d` calls and drained by the renderer once `scene()` returns.
+#[derive(Debug, Clone)]
+pub enum DrawCommand {
+    Cube {
+        x: f32,
+        y: f32,
+        z: f32,
+        size: f32,
+        color: [u8; 3],
+    },
+    Line3 {
+        x0: f32,
+        y0: f32,
+        z0: f32,
+        x1: f32,
+        y1: f32,
+        z1: f32,
+        color: [u8; 3],
+    },
+}
+
+fn color_from_array(color: &rhai::Array) -> [u8; 3] {
+    let component = |i: usize| {
+        color
+            .get(i)
+            .and_then(|v| v.as_int().ok())
+            .unwrap_or(255)
+            .clamp(0, 255) as u8
+    };
+    [component(0), component(1), component(2)]
+}
+
+/// Drone state handed to a script's `event()` hook each frame, so it can react to the simulation
+/// (e.g. switch camera once above some altitude) without reaching into `Renderer` itself.
+#[derive(Debug, Clone, Copy)]
+pub struct SceneState {
+    pub position: [f32; 3],
+    pub velocity: [f32; 3],
+    /// Seconds since the scene script was loaded.
+    pub elapsed: f32,
+}
+
+/// Startup policy a script's `config()` hook can set, read once when the script is compiled.
+/// Anything the hook doesn't mention keeps its `Default`.
+#[derive(Debug, Clone)]
+pub struct SceneConfig {
+    pub show_hud: bool,
+    pub starfield_enabled: bool,
+    pub starting_camera_mode: Option<crate::CameraMode>,
+}
+
+impl Default for SceneConfig {
+    fn default() -> Self {
+        Self {
+            show_hud: true,
+            starfield_enabled: true,
+            starting_camera_mode: None,
+        }
+    }
+}
+
+/// A single thing a script's `event()` hook asked the renderer to do this frame.
+#[derive(Debug, Clone)]
+pub enum SceneAction {
+    SetCameraMode(crate::CameraMode),
+    SetFovDegrees(f32),
+    Reset,
+    GentleStop,
+    /// Overrides `render_hud`'s usual FPS line with this text instead; the gauges still draw.
+    HudText(String),
+}
+
+/// Maps the `camera_mode` string a script's `config()`/`event()` hook returns onto a
+/// `CameraMode` variant. Unrecognized names are ignored (`None`) rather than erroring, so a typo
+/// in a script just keeps the previous mode instead of aborting the frame.
+fn parse_camera_mode(name: &str) -> Option<crate::CameraMode> {
+    match name {
+        "third_person" => Some(crate::CameraMode::ThirdPerson),
+        "flycam" => Some(crate::CameraMode::Flycam),
+        "free_fly" => Some(crate::CameraMode::FreeFly),
+        "first_person" => Some(crate::CameraMode::FirstPerson),
+        _ => None,
+    }
+}
+
+/// Reads whichever keys a script's `config()` hook chose to set, leaving the rest at their
+/// `SceneConfig::default()`.
+fn config_from_map(map: &rhai::Map) -> SceneConfig {
+    let mut config = SceneConfig::default();
+
+    if let Some(value) = map.get("show_hud").and_then(|v| v.as_bool().ok()) {
+        config.show_hud = value;
+    }
+    if let Some(value) = map.get("starfield").and_then(|v| v.as_bool().ok()) {
+        config.starfield_enabled = value;
+    }
+    if let Some(name) = map
+        .get("camera_mode")
+        .and_then(|v| v.clone().into_string().ok())
+    {
+        config.starting_camera_mode = parse_camera_mode(&name);
+    }
+
+    config
+}
+
+/// Parses a script's `event()` return value into a `SceneAction`. `None` covers both "the script
+/// returned nothing this frame" and "the action/mode name wasn't recognized" - either way, the
+/// renderer just does nothing for this frame rather than treating it as an error.
+fn action_from_map(map: &rhai::Map) -> Option<SceneAction> {
+    match map.get("action")?.clone().into_string().ok()?.as_str() {
+        "camera_mode" => {
+            let name = map.get("mode")?.clone().into_string().ok()?;
+            parse_camera_mode(&name).map(SceneAction::SetCameraMode)
+        }
+        "fov" => map
+            .get("degrees")?
+            .as_float()
+            .ok()
+            .map(|d| SceneAction::SetFovDegrees(d as f32)),
+        "reset" => Some(SceneAction::Reset),
+        "gentle_stop" => Some(SceneAction::GentleStop),
+        "hud_text" => map
+            .get("text")?
+            .clone()
+            .into_string()
+            .ok()
+            .map(SceneAction::HudText),
+        _ => None,
+    }
+}
+
+/// A compiled scene script. Compiled once when loaded; `run_frame` re-evaluates its `scene()`
+/// function every render, with `drone_pos()` inside the script resolving to whatever position
+/// `run_frame` was called with. Its optional `config()` function is read once at compile time,
+/// and its optional `event()` function once per simulation step - see `config`/`run_event`.
+pub struct SceneScript {
+    engine: rhai::Engine,
+    ast: rhai::AST,
+    scope: rhai::Scope<'static>,
+    commands: Rc<RefCell<Vec<DrawCommand>>>,
+    drone_pos: Rc<RefCell<[f32; 3]>>,
+    config: SceneConfig,
+    /// Last script error, if any - the renderer falls back to the hardcoded scene rather than
+    /// drawing nothing or aborting the frame.
+    last_error: Option<String>,
+}
+
+impl SceneScript {
+    /// Compiles `path` and registers the drawing API it can call into.
+    pub fn compile(path: &str) -> Result<Self, Box<rhai::EvalAltResult>> {
+        let commands: Rc<RefCell<Vec<DrawCommand>>> = Rc::new(RefCell::new(Vec::new()));
+        let drone_pos: Rc<RefCell<[f32; 3]>> = Rc::new(RefCell::new([0.0, 0.0, 0.0]));
+
+        let mut engine = rhai::Engine::new();
+
+        let cmds = commands.clone();
+        engine.register_fn(
+            "draw_cube",
+            move |x: f64, y: f64, z: f64, size: f64, color: rhai::Array| {
+                cmds.borrow_mut().push(DrawCommand::Cube {
+                    x: x as f32,
+                    y: y as f32,
+                    z: z as f32,
+                    size: size as f32,
+                    color: color_from_array(&color),
+                });
+            },
+        );
+
+        let cmds = commands.clone();
+        engine.register_fn(
+            "draw_line3d",
+            move |x0: f64, y0: f64, z0: f64, x1: f64, y1: f64, z1: f64, color: rhai::Array| {
+                cmds.borrow_mut().push(DrawCommand::Line3 {
+                    x0: x0 as f32,
+                    y0: y0 as f32,
+                    z0: z0 as f32,
+                    x1: x1 as f32,
+                    y1: y1 as f32,
+                    z1: z1 as f32,
+                    color: color_from_array(&color),
+                });
+            },
+        );
+
+        let pos_for_query = drone_pos.clone();
+        engine.register_fn("drone_pos", move || {
+            let p = *pos_for_query.borrow();
+            vec![p[0] as f64, p[1] as f64, p[2] as f64]
+        });
+
+        let ast = engine.compile_file(path.into())?;
+        let mut scope = rhai::Scope::new();
+
+        // `config()` is optional - a script without one (or whose `config()` errors) gets
+        // `SceneConfig::default()`: HUD and starfield on, camera mode left as it was.
+        let config = match engine.call_fn::<rhai::Map>(&mut scope, &ast, "config", ()) {
+            Ok(map) => config_from_map(&map),
+            Err(_) => SceneConfig::default(),
+        };
+
+        Ok(Self {
+            engine,
+            ast,
+            scope,
+            commands,
+            drone_pos,
+            config,
+            last_error: None,
+        })
+    }
+
+    /// The script's startup policy, read once from its optional `config()` function when
+    /// compiled.
+    pub fn config(&self) -> &SceneConfig {
+        &self.config
+    }
+
+    /// Calls the script's optional `event()` function with this frame's drone state, returning
+    /// whatever action it asked for. `None` if the script defines no `event()` function, the call
+    /// errors, or it simply didn't return an action this frame.
+    pub fn run_event(&mut self, state: SceneState) -> Option<SceneAction> {
+        let mut state_map = rhai::Map::new();
+        state_map.insert("x".into(), (state.position[0] as f64).into());
+        state_map.insert("y".into(), (state.position[1] as f64).into());
+        state_map.insert("z".into(), (state.position[2] as f64).into());
+        state_map.insert("vx".into(), (state.velocity[0] as f64).into());
+        state_map.insert("vy".into(), (state.velocity[1] as f64).into());
+        state_map.insert("vz".into(), (state.velocity[2] as f64).into());
+        state_map.insert("elapsed".into(), (state.elapsed as f64).into());
+
+        let result = self
+            .engine
+            .call_fn::<rhai::Map>(&mut self.scope, &self.ast, "event", (state_map,))
+            .ok()?;
+        action_from_map(&result)
+    }
+
+    /// Runs the script's `scene()` function for one frame against `drone_position`, returning the
+    /// commands it drew. Returns `None` (leaving `last_error` set) on a script error, so the
+    /// caller can fall back to its hardcoded scene instead of rendering nothing.
+    pub fn run_frame(&mut self, drone_position: [f32; 3]) -> Option<Vec<DrawCommand>> {
+        *self.drone_pos.borrow_mut() = drone_position;
+        self.commands.borrow_mut().clear();
+
+        match self
+            .engine
+            .call_fn::<()>(&mut self.scope, &self.ast, "scene", ())
+        {
+            Ok(()) => {
+                self.last_error = None;
+                Some(self.commands.borrow().clone())
+            }
+            Err(err) => {
+                self.last_error = Some(err.to_string());
+                None
+            }
+        }
+    }
+
+    pub fn last_error(&self) -> Option<&str> {
+        self.last_error.as_deref()
+    }
+}